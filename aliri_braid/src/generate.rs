@@ -0,0 +1,150 @@
+//! Generating random values
+//!
+//! These functions back the `generate()` and `generate_with()` constructors generated for
+//! braids declared with `generate(len = N, alphabet = "...")`. Values are drawn uniformly
+//! from an [`Alphabet`] using a cryptographically secure random number generator.
+//!
+//! ```
+//! use aliri_braid::braid;
+//!
+//! #[braid(secret, generate(len = 32, alphabet = "base62"))]
+//! pub struct ApiKey;
+//!
+//! let key = ApiKey::generate();
+//! assert_eq!(32, key.as_str().len());
+//! assert!(key.as_str().bytes().all(|b| b.is_ascii_alphanumeric()));
+//! ```
+//!
+//! The `generate_with()` constructor accepts any [`CryptoRngCore`], so that generation can be
+//! made deterministic in tests by providing a seeded generator.
+//!
+//! [`CryptoRngCore`]: rand_core::CryptoRngCore
+
+use alloc::string::String;
+
+/// Re-export of the [`rand_core`] crate, which defines the random number generator traits
+pub use rand_core;
+use rand_core::CryptoRngCore;
+
+/// The maximum number of values generated while searching for one accepted by a validator
+pub const MAX_ATTEMPTS: usize = 64;
+
+/// A set of ASCII characters from which random values are drawn
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Alphabet(&'static str);
+
+impl Alphabet {
+    /// The digits, followed by the upper- and lowercase ASCII letters
+    pub const BASE62: Self =
+        Self::new("0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz");
+
+    /// The URL-safe base64 alphabet from RFC 4648
+    pub const BASE64URL: Self =
+        Self::new("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_");
+
+    /// The base32 alphabet from RFC 4648
+    pub const BASE32: Self = Self::new("ABCDEFGHIJKLMNOPQRSTUVWXYZ234567");
+
+    /// The lowercase hexadecimal digits
+    pub const HEX: Self = Self::new("0123456789abcdef");
+
+    /// The decimal digits
+    pub const DIGITS: Self = Self::new("0123456789");
+
+    /// Constructs an alphabet from the given symbols
+    ///
+    /// # Panics
+    ///
+    /// Panics if `symbols` is empty, contains a non-ASCII character, or contains the same
+    /// character more than once.
+    pub const fn new(symbols: &'static str) -> Self {
+        let bytes = symbols.as_bytes();
+        assert!(!bytes.is_empty(), "alphabet must not be empty");
+
+        let mut i = 0;
+        while i < bytes.len() {
+            assert!(
+                bytes[i].is_ascii(),
+                "alphabet must only contain ASCII characters"
+            );
+            let mut j = i + 1;
+            while j < bytes.len() {
+                assert!(bytes[i] != bytes[j], "alphabet must not repeat characters");
+                j += 1;
+            }
+            i += 1;
+        }
+
+        Self(symbols)
+    }
+
+    /// The symbols in the alphabet
+    pub const fn symbols(&self) -> &'static str {
+        self.0
+    }
+
+    /// The number of symbols in the alphabet
+    pub const fn size(&self) -> usize {
+        self.0.len()
+    }
+}
+
+/// Generates a value of `len` symbols drawn uniformly from `alphabet` using `rng`
+pub fn generate_with<R: CryptoRngCore + ?Sized>(
+    rng: &mut R,
+    len: usize,
+    alphabet: Alphabet,
+) -> String {
+    let symbols = alphabet.symbols().as_bytes();
+
+    // Bytes at or above the largest multiple of the alphabet size are rejected, so that
+    // every symbol is equally likely
+    let size = symbols.len();
+    let limit = 256 - 256 % size;
+
+    let mut value = String::with_capacity(len);
+    let mut buf = [0u8; 64];
+    while value.len() < len {
+        rng.fill_bytes(&mut buf);
+        for &b in buf.iter().filter(|&&b| usize::from(b) < limit) {
+            if value.len() == len {
+                break;
+            }
+            value.push(char::from(symbols[usize::from(b) % size]));
+        }
+    }
+
+    crate::wipe::wipe(&mut buf);
+    value
+}
+
+/// Generates values with [`generate_with()`] until one is accepted by `check`
+///
+/// # Errors
+///
+/// Returns the error from the last attempt if [`MAX_ATTEMPTS`] values are rejected.
+pub fn generate_valid_with<R, T, E>(
+    rng: &mut R,
+    len: usize,
+    alphabet: Alphabet,
+    mut check: impl FnMut(String) -> Result<T, E>,
+) -> Result<T, E>
+where
+    R: CryptoRngCore + ?Sized,
+{
+    let mut attempts = 1;
+    loop {
+        match check(generate_with(rng, len, alphabet)) {
+            Err(_) if attempts < MAX_ATTEMPTS => attempts += 1,
+            result => return result,
+        }
+    }
+}
+
+/// The operating system's random number generator
+///
+/// Generating a value with this generator panics if the operating system fails to provide
+/// random data.
+pub fn os_rng() -> rand_core::OsRng {
+    rand_core::OsRng
+}
@@ -195,6 +195,35 @@
 //! }
 //! ```
 //!
+//! Mutating the inner value directly bypasses the validator or normalizer of a braid, and
+//! may leave it holding a value that it should have rejected. Validated and normalized
+//! braids instead provide `edit()`, which passes a copy of the inner value to a closure,
+//! and then checks the result. If the edited value is rejected, the braid keeps its
+//! previous value and the error is returned. Braids with a `context_validator` provide
+//! `edit_with()` in its place, which takes the context to check the edited value against.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(validate(non_empty, charset = "a-z0-9:/"))]
+//! pub struct AmazonArnBuf;
+//!
+//! impl AmazonArnBuf {
+//!     /// Append an ARN segment
+//!     pub fn add_segment(&mut self, segment: &str) -> Result<(), InvalidAmazonArnBuf> {
+//!         self.edit(|inner| {
+//!             inner.push_str(":");
+//!             inner.push_str(segment);
+//!         })
+//!     }
+//! }
+//!
+//! let mut arn = AmazonArnBuf::from_static("arn:aws");
+//! arn.add_segment("iam").unwrap();
+//! assert!(arn.add_segment("IAM").is_err());
+//! assert_eq!("arn:aws:iam", arn.as_str());
+//! ```
+//!
 //! # Encapsulation
 //!
 //! Because code within the same module where the braid is defined are allowed to
@@ -262,8 +291,8 @@
 //! you have a few options:
 //!
 //! 1. Pass the username around as a string, validate that it isn't `root` at known entry points.
-//! 2. Create a username type and allow creation from a raw string, then validate it
-//!    just after creation.
+//! 2. Create a username type and allow creation from a raw string, then validate it just after
+//!    creation.
 //! 3. Create a strong username type that requires the value to be validated prior to being
 //!    creatable.
 //!
@@ -399,6 +428,203 @@
 //! }
 //! ```
 //!
+//! ### Declarative rules
+//!
+//! Common rules can be declared with `validate(...)` instead of implementing
+//! `Validator` by hand. The macro generates the `Validator` implementation along
+//! with an error enum named `Invalid` followed by the type name, which implements
+//! `Display`, `Error`, and `From<Infallible>`. The available rules are `non_empty`,
+//! `min_len = N`, `max_len = N`, `prefix = "..."`, and `charset = "..."`, which
+//! lists the characters and ranges of characters allowed after the prefix. Lengths
+//! are measured in bytes.
+//!
+//! ```
+//! use aliri_braid::braid;
+//!
+//! #[braid(validate(non_empty, max_len = 64, charset = "a-z0-9_-", prefix = "usr_"))]
+//! pub struct UserId;
+//!
+//! assert!(UserId::new("usr_alice".to_string()).is_ok());
+//! assert_eq!(
+//!     InvalidUserId::MissingPrefix,
+//!     UserId::new("alice".to_string()).unwrap_err(),
+//! );
+//! assert_eq!(
+//!     InvalidUserId::InvalidChar { ch: 'A', offset: 4 },
+//!     UserId::new("usr_Alice".to_string()).unwrap_err(),
+//! );
+//! ```
+//!
+//! ### Built-in and combined validators
+//!
+//! The [`validators`] module provides validators for common cases, such as
+//! [`validators::NonEmpty`], [`validators::MaxLen`], and [`validators::AsciiPrintable`],
+//! along with the [`validators::Lowercase`] and [`validators::TrimWhitespace`]
+//! normalizers. These report a [`ValidationError`], which identifies the broken rule and
+//! the byte offset of the offending part of the value, and can render the value with that
+//! part underlined. The braid adds its name to the error, and marks the error as redacted
+//! if it is a secret, so that rendering does not reveal the value. The error enums
+//! generated by `validate(...)` also convert into a `ValidationError`.
+//!
+//! Validators can be combined at the type level without a hand-written
+//! implementation. [`validators::All`] requires every validator in a tuple to accept
+//! the value, [`validators::Any`] requires at least one to, [`validators::Not`] inverts
+//! a validator, and [`validators::Map`] converts a validator's error into another type.
+//! The error of `All` is a [`validators::AllError`], whose variant identifies the
+//! validator that rejected the value.
+//!
+//! ```
+//! use aliri_braid::{
+//!     braid,
+//!     validators::{All, AllError, MaxLen, Strength},
+//! };
+//!
+//! #[braid(secret, validator = "All<(MaxLen<64>, Strength)>")]
+//! pub struct Password;
+//!
+//! let err = Password::new("x".repeat(65)).unwrap_err();
+//! let AllError::First(err) = err.get_ref() else { unreachable!() };
+//! assert_eq!("max_len", err.rule());
+//! assert_eq!(
+//!     "invalid `Password`: value is longer than the maximum length\n  | [redacted]",
+//!     err.render(&"x".repeat(65)).to_string(),
+//! );
+//! ```
+//!
+//! ### Reporting every failure
+//!
+//! `new()` stops at the first rule that a value breaks. Validated braids also provide
+//! `try_new_collecting()`, which checks the value with [`Validator::validate_all()`] and
+//! returns an error for every broken rule, which is useful when reporting problems with
+//! user input. Normalized braids provide it as well, and check the value once it has been
+//! normalized. Validators generated by `validate(...)` and the [`validators::All`] and
+//! [`validators::Map`] combinators check every rule, while other validators report their
+//! single error unless they override `validate_all()`.
+//!
+//! ```
+//! use aliri_braid::braid;
+//!
+//! #[braid(validate(max_len = 8, charset = "a-z"))]
+//! pub struct Nickname;
+//!
+//! assert_eq!(
+//!     vec![
+//!         InvalidNickname::TooLong { len: 9 },
+//!         InvalidNickname::InvalidChar { ch: 'B', offset: 0 },
+//!         InvalidNickname::InvalidChar { ch: '!', offset: 8 },
+//!     ],
+//!     Nickname::try_new_collecting("Bobbybob!".to_owned()).unwrap_err(),
+//! );
+//! ```
+//!
+//! ### Compile-time validation
+//!
+//! `from_static()` on the borrowed form of a validated braid panics if the value is
+//! invalid, so a typo in a constant may go unnoticed until that code runs. With the
+//! `const_validator` option, the validator type provides an inherent
+//! `const fn validate_const(raw: &str) -> Result<(), &'static str>`, and `from_static()`
+//! becomes a `const fn` that checks the value with it. An invalid value used to define a
+//! constant then fails to compile. Braids using `validate(...)` generate `validate_const()`
+//! from their rules, and so always have a `const` `from_static()`.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! # #[derive(Debug)]
+//! # pub struct InvalidUsername(&'static str);
+//! # aliri_braid::from_infallible!(InvalidUsername);
+//! #
+//! #[braid(validator, const_validator)]
+//! pub struct Username;
+//!
+//! impl Username {
+//!     pub const fn validate_const(raw: &str) -> Result<(), &'static str> {
+//!         if raw.is_empty() {
+//!             Err("username cannot be empty")
+//!         } else {
+//!             Ok(())
+//!         }
+//!     }
+//! }
+//!
+//! impl aliri_braid::Validator for Username {
+//!     type Error = InvalidUsername;
+//!
+//!     fn validate(raw: &str) -> Result<(), Self::Error> {
+//!         Self::validate_const(raw).map_err(InvalidUsername)
+//!     }
+//! }
+//!
+//! const ADMIN: &UsernameRef = UsernameRef::from_static("admin");
+//! ```
+//!
+//! ```compile_fail
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(validate(non_empty, prefix = "usr_"))]
+//! pub struct UserId;
+//!
+//! const ADMIN: &UserIdRef = UserIdRef::from_static("admin");
+//! # fn main() { let _ = ADMIN; }
+//! ```
+//!
+//! ### Context-dependent validation
+//!
+//! Some rules depend on information only known at runtime, such as the set of
+//! tenants in a loaded configuration. A validator can check these by also
+//! implementing [`ContextValidator`], whose `Context` type holds that information.
+//! With the `context_validator` option, the braid gains a `new_with()` constructor on
+//! the owned form and a `from_str_with()` constructor on the borrowed form, which take
+//! a reference to the context and check both sets of rules. Other constructors, and
+//! plain deserialization, only check the rules of [`Validator`].
+//!
+//! If the braid implements `serde`, a `DeserializeSeed` named after the owned type
+//! with a `Seed` suffix is also generated, which holds a reference to the context.
+//!
+//! ```
+//! use std::collections::HashSet;
+//!
+//! use aliri_braid::{braid, validators::NonEmpty, ContextValidator, ValidationError, Validator};
+//! use serde::de::DeserializeSeed;
+//!
+//! pub struct Config {
+//!     tenants: HashSet<String>,
+//! }
+//!
+//! #[braid(serde, validator, context_validator)]
+//! pub struct Tenant;
+//!
+//! impl Validator for Tenant {
+//!     type Error = ValidationError;
+//!
+//!     fn validate(raw: &str) -> Result<(), Self::Error> {
+//!         NonEmpty::validate(raw)
+//!     }
+//! }
+//!
+//! impl ContextValidator for Tenant {
+//!     type Context = Config;
+//!
+//!     fn validate_with(config: &Config, raw: &str) -> Result<(), Self::Error> {
+//!         if config.tenants.contains(raw) {
+//!             Ok(())
+//!         } else {
+//!             Err(ValidationError::new("known_tenant", "unknown tenant", 0, raw.len()))
+//!         }
+//!     }
+//! }
+//!
+//! let config = Config {
+//!     tenants: HashSet::from(["acme".to_owned()]),
+//! };
+//!
+//! assert!(Tenant::new_with(&config, "acme".to_owned()).is_ok());
+//! assert!(TenantRef::from_str_with(&config, "globex").is_err());
+//! assert!(TenantRef::from_str("globex").is_ok());
+//!
+//! let mut json = serde_json::Deserializer::from_str(r#""globex""#);
+//! assert!(TenantSeed(&config).deserialize(&mut json).is_err());
+//! ```
+//!
 //! ## Normalization
 //!
 //! Braided strings can also have enforced normalization, which is carried out at the creation
@@ -648,6 +874,25 @@
 //! assert_not_impl_any!(Sensitive: Clone);
 //! ```
 //!
+//! Alternatively, `clone = "explicit"` replaces the `Clone` implementation with an inherent
+//! `duplicate()` method, so that values can still be copied, but only where each copy is
+//! easy to find in review. For `secret` braids with an `audit` hook, each copy is reported
+//! as a [`SecretAccess::Duplicate`].
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! # use static_assertions::assert_not_impl_any;
+//! #
+//! #[braid(clone = "explicit")]
+//! pub struct Sensitive;
+//!
+//! assert_not_impl_any!(Sensitive: Clone);
+//!
+//! let value = Sensitive::from_static("secret value");
+//! let copy = value.duplicate();
+//! # assert_eq!(value, copy);
+//! ```
+//!
 //! ## Custom `Display`, `Debug`, and `PartialOrd`/`Ord` implementations
 //!
 //! By default, the implementations of [`Display`][core::fmt::Display], [`Debug`][core::fmt::Debug]
@@ -703,6 +948,334 @@
 //! assert_eq!("secret value", borrowed.as_str());
 //! ```
 //!
+//! ## Secrets
+//!
+//! Braids that hold sensitive values, such as API keys or passwords, can be marked with the
+//! `secret` parameter. This replaces the `Debug` and `Display` implementations with ones that
+//! redact the value, so that it does not end up in logs by accident. The value can still be
+//! revealed by formatting with the alternate flag (`{:#}` or `{:#?}`).
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(secret)]
+//! pub struct ApiKey;
+//!
+//! let key = ApiKey::from_static("hunter2");
+//! assert_eq!("[redacted ApiKey]", format!("{:?}", key));
+//! assert_eq!("[redacted ApiKey]", format!("{}", key));
+//! assert_eq!("hunter2", format!("{:#}", key));
+//! ```
+//!
+//! How the value is redacted can be configured with the list form of the parameter. A fixed
+//! message can be given with `message = "..."`, all but the last few characters can be masked
+//! with `last = N`, or only the length of the value can be shown with `length_only`. Revealing
+//! the value with the alternate flag can be disabled entirely with `none`. These options apply
+//! to both `Debug` and `Display`, but can be given separately for each by nesting them inside
+//! `debug(...)` or `display(...)`. Helpers implementing these strategies are available in the
+//! [`redaction`] module.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(secret(last = 4))]
+//! pub struct CardNumber;
+//!
+//! #[braid(secret(length_only, none))]
+//! pub struct SessionToken;
+//!
+//! #[braid(secret(message = "<email>", debug(last = 12)))]
+//! pub struct EmailAddress;
+//!
+//! let card = CardNumber::from_static("4242424242424242");
+//! assert_eq!("************4242", format!("{}", card));
+//!
+//! let token = SessionToken::from_static("hunter2");
+//! assert_eq!("[redacted, 7 chars]", format!("{:#?}", token));
+//!
+//! let email = EmailAddress::from_static("jane@example.com");
+//! assert_eq!("<email>", format!("{}", email));
+//! assert_eq!("****@example.com", format!("{:?}", email));
+//! ```
+//!
+//! To make it possible to tell whether two redacted values are the same without revealing
+//! them, `secret(fingerprint)` includes a short keyed hash of the value in the redaction
+//! message, and adds a `fingerprint()` method to the borrowed form. The key is set once for
+//! the whole process with [`redaction::set_fingerprint_key()`], and should be generated
+//! randomly at startup. Until it is set, no fingerprint is shown.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(secret(fingerprint))]
+//! pub struct ApiKey;
+//!
+//! let key = ApiKey::from_static("hunter2");
+//! assert_eq!("[redacted ApiKey]", format!("{:?}", key));
+//!
+//! aliri_braid::redaction::set_fingerprint_key([0x5a; 16]).unwrap();
+//! let fingerprint = key.fingerprint().unwrap();
+//! assert_eq!(format!("[redacted ApiKey#{}]", fingerprint), format!("{:?}", key));
+//! ```
+//!
+//! Whether secrets are redacted at all can be changed at runtime for the whole process with
+//! [`redaction::set_policy()`]. [`Policy::Reveal`](redaction::Policy::Reveal) formats secrets
+//! other than `strict` ones as if they were not secret, which can be useful during local
+//! development, while [`Policy::Fingerprint`](redaction::Policy::Fingerprint) redacts every
+//! secret with its fingerprint. Production builds can call [`redaction::lock_policy()`] at startup
+//! to ensure that secrets can no longer be revealed this way. With the `std` feature enabled,
+//! `redaction::with_policy()` overrides the policy for only the current thread, which is useful
+//! in tests.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! use aliri_braid::redaction::{self, Policy};
+//!
+//! #[braid(secret)]
+//! pub struct ApiKey;
+//!
+//! let key = ApiKey::from_static("hunter2");
+//! redaction::set_policy(Policy::Reveal).unwrap();
+//! assert_eq!("hunter2", key.to_string());
+//!
+//! redaction::lock_policy();
+//! assert_eq!("[redacted ApiKey]", key.to_string());
+//! ```
+//!
+//! Equality comparisons between secret values, whether owned, borrowed, or boxed, are made in
+//! constant time using [`constant_time_eq()`], so that comparing against a secret does not
+//! reveal how much of it matched. Because ordering comparisons cannot be made without leaking
+//! such information, `PartialOrd` and `Ord` are omitted for secrets unless explicitly requested
+//! with the `ord` parameter.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! # use static_assertions::{assert_impl_all, assert_not_impl_any};
+//! #
+//! #[braid(secret)]
+//! pub struct WebhookSignature;
+//!
+//! #[braid(secret, ord = "impl")]
+//! pub struct SortableSecret;
+//!
+//! assert_not_impl_any!(WebhookSignature: PartialOrd, Ord);
+//! assert_impl_all!(SortableSecret: PartialOrd, Ord);
+//! ```
+//!
+//! Secrets can also be made harder to expose by accident with `secret = "strict"` (or
+//! `secret(strict)`). Strict secrets have no `as_str()` method, no `take()` method, and do not
+//! implement `AsRef<str>`, `Borrow<str>`, or `From<Owned> for String`, so they cannot be passed
+//! implicitly to functions expecting a string. Instead, the value must be accessed explicitly
+//! with `expose_secret()`, or scoped to a closure with `with_exposed()`, making each use easy
+//! to find and review. For the same reason, strict secrets ignore the alternate flag when
+//! formatted.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(secret = "strict")]
+//! pub struct Password;
+//!
+//! let password = Password::from_static("hunter2");
+//! assert_eq!("hunter2", password.expose_secret());
+//! assert_eq!(7, password.with_exposed(|s| s.len()));
+//! ```
+//!
+//! ```compile_fail
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(secret = "strict")]
+//! pub struct Password;
+//!
+//! let password = Password::from_static("hunter2");
+//! let _: &str = password.as_ref();
+//! ```
+//!
+//! Access to the raw value of a secret can be audited with `secret(audit = "Hook")`, where
+//! `Hook` implements [`SecretAccessHook`]. The hook is notified, with the name of the type
+//! and the kind of [`SecretAccess`], whenever the value is borrowed as a string, unwrapped,
+//! revealed by formatting, or serialized with `serde = "expose"` or `serde = "sealed"`. The
+//! redacted forms written by `serde = "redact"` are not reported. Borrowing the value through
+//! `as_str()` or `expose_secret()` is no longer possible in `const` contexts when audited.
+//!
+//! One-time credentials, such as password reset tokens, can be declared with `secret(once)`.
+//! Such secrets are `strict`, and their borrowed form provides no access to the value at all.
+//! Instead, the value is read with `take_once()`, which moves it out of the owned form and
+//! returns an [`AlreadyTaken`] error on any later call, even if the value was empty. The value
+//! is never revealed by formatting, and it cannot be copied: neither the owned form nor the
+//! borrowed form implements `Clone` or `ToOwned`, and no conversions create a new owned form,
+//! `Rc`, or `Arc` from the borrowed form. For the same reason, `once` secrets cannot be
+//! normalized or serialized with `serde = "expose"` or `serde = "sealed"`. The owned form
+//! records whether its value has been taken in an additional private field, so it should be
+//! created through its constructors rather than a struct expression.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(secret(once))]
+//! pub struct ResetToken;
+//!
+//! let mut token = ResetToken::from_static("hunter2");
+//! assert_eq!("hunter2", token.take_once().unwrap());
+//! assert!(token.take_once().is_err());
+//! ```
+//!
+//! Validation errors often describe the rejected input, which for a secret is the secret
+//! itself. The validator and normalizer errors of secret braids are therefore wrapped in
+//! [`redaction::RedactedError`], which only names the type when formatted. This applies to
+//! the errors returned by constructors and conversions, to deserialization errors, and to
+//! panics from `from_static()`. The original error remains available through
+//! `get_ref()` and `into_inner()`, and its `source()` is preserved.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! # #[derive(Debug)]
+//! # pub struct InvalidApiKey(String);
+//! # impl std::fmt::Display for InvalidApiKey {
+//! #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+//! #         write!(f, "invalid API key: {}", self.0)
+//! #     }
+//! # }
+//! # aliri_braid::from_infallible!(InvalidApiKey);
+//! #
+//! #[braid(secret, validator)]
+//! pub struct ApiKey;
+//!
+//! impl aliri_braid::Validator for ApiKey {
+//!     type Error = InvalidApiKey;
+//!
+//!     fn validate(raw: &str) -> Result<(), Self::Error> {
+//!         if raw.starts_with("key_") {
+//!             Ok(())
+//!         } else {
+//!             Err(InvalidApiKey(raw.to_owned()))
+//!         }
+//!     }
+//! }
+//!
+//! let err = ApiKey::new("hunter2".to_owned()).unwrap_err();
+//! assert_eq!("invalid value for `ApiKey`", err.to_string());
+//! assert_eq!("hunter2", err.into_inner().0);
+//! ```
+//!
+//! Passwords and similar secrets chosen by people can be checked with
+//! [`validators::Strength`], which rejects values containing banned words, lacking required
+//! classes of characters, or with too little estimated entropy. Its errors never include the
+//! value.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(secret, validator = "aliri_braid::validators::Strength")]
+//! pub struct Password;
+//!
+//! assert!(Password::new("correct horse battery staple".to_owned()).is_ok());
+//! assert!(Password::new("Password123!".to_owned()).is_err());
+//! ```
+//!
+//! With the `std` feature enabled, `secret(load)` adds `from_env()` and `from_file()`
+//! constructors, which validate or normalize the value as `FromStr` would. Any buffers used
+//! to read the value are wiped, and the resulting `load::LoadError` never includes the value.
+//! A single trailing line ending is removed from values read from a file.
+//!
+//! ```
+//! # #[cfg(feature = "std")]
+//! # {
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(secret(load))]
+//! pub struct DatabasePassword;
+//!
+//! let err = DatabasePassword::from_env("DATABASE_PASSWORD_NOT_SET").unwrap_err();
+//! assert_eq!(
+//!     "failed to load `DatabasePassword` from environment variable \
+//!      `DATABASE_PASSWORD_NOT_SET`: not present",
+//!     err.to_string(),
+//! );
+//! # }
+//! ```
+//!
+//! With the `generate` feature enabled, `generate(len = N, alphabet = "...")` adds a
+//! `generate()` constructor that draws a random value from the operating system's random
+//! number generator, along with `generate_with()`, which accepts a seeded generator for use
+//! in tests. Generated values must still pass the braid's validator; rejected values are
+//! discarded and generated again. The alphabets are listed in the `generate` module.
+//!
+//! ```
+//! # #[cfg(feature = "generate")]
+//! # {
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(secret, generate(len = 32, alphabet = "base62"))]
+//! pub struct ClientSecret;
+//!
+//! let secret = ClientSecret::generate();
+//! assert_eq!(32, secret.as_str().len());
+//! # }
+//! ```
+//!
+//! With the `scrub` feature enabled, `secret(scrub)` registers each owned value with the
+//! global `Scrubber` for as long as it is alive, so that it can be removed from text
+//! produced elsewhere, such as error messages from other libraries. Each occurrence is
+//! replaced with the value's redaction message, which ignores the runtime [redaction
+//! policy](redaction::Policy) and never includes a fingerprint.
+//!
+//! ```
+//! # #[cfg(feature = "scrub")]
+//! # {
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(secret(scrub))]
+//! pub struct SessionToken;
+//!
+//! let token = SessionToken::from_static("hunter2");
+//! let err = format!("connection to wss://example.com/?token={} failed", token.as_str());
+//! assert_eq!(
+//!     "connection to wss://example.com/?token=[redacted SessionToken] failed",
+//!     aliri_braid::Scrubber::global().scrub(&err),
+//! );
+//! # }
+//! ```
+//!
+//! With the `zeroize` feature enabled, `secret(zeroize)` will additionally wipe the memory
+//! holding the value when it is dropped. This applies to the owned form as well as to
+//! `Box`, `Rc`, and `Arc` pointers to the borrowed form. Conversions that consume the owned
+//! form, such as `into_boxed_ref()`, avoid leaving unwiped copies of the value behind.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(secret(zeroize))]
+//! pub struct RefreshToken;
+//!
+//! let token = RefreshToken::from_static("hunter2");
+//! let boxed = token.into_boxed_ref();
+//! # assert_eq!("hunter2", boxed.as_str());
+//! drop(boxed); // memory is wiped before being freed
+//! ```
+//!
+//! When wrapping a [custom string type](#custom-string-types), that type must implement
+//! `zeroize::Zeroize`.
+//!
+//! With the `mlock` feature enabled, a secret can be kept out of swap by wrapping
+//! `locked::LockedString`, which locks the memory holding the value with `mlock` and wipes
+//! it when dropped. If the memory cannot be locked, the value is still stored, and the
+//! failure is counted by `locked::lock_failures()`. Braids wrapping it can only implement
+//! `serde` traits if the `serde` feature is also enabled.
+//!
+//! ```
+//! # #[cfg(feature = "mlock")]
+//! # {
+//! use aliri_braid::{braid, locked::LockedString};
+//!
+//! #[braid(secret)]
+//! pub struct SigningKey(LockedString);
+//! #
+//! # let key = SigningKey::from_static("hunter2");
+//! # assert_eq!("hunter2", key.as_str());
+//! # }
+//! ```
+//!
 //! # Serde
 //!
 //! [`Serialize`] and [`Deserialize`] implementations from the [`serde`] crate
@@ -765,6 +1338,50 @@
 //! assert!(serde_json::from_str::<&UsernameRef>("\"nobody\"").is_ok());
 //! ```
 //!
+//! ## Secrets
+//!
+//! Serializing a [secret](#secrets) braid requires choosing how its value is treated, so
+//! that a secret nested inside a larger serialized structure does not leak by accident.
+//! With `serde = "redact"`, the value is serialized as the same redaction message used by
+//! its `Display` implementation. With `serde = "error"`, serialization fails with an error
+//! that does not include the value. Serializing the raw value must be requested explicitly
+//! with `serde = "expose"`. Deserialization is the same for all modes.
+//!
+//! With the `seal` feature enabled, secrets that must be persisted, such as refresh tokens
+//! stored in a database, can instead be serialized with `serde = "sealed"`, which encrypts
+//! the value with the process-wide sealer installed with `seal::set_sealer()`.
+//! Deserialization unseals the value and then validates it as usual. The sealed form
+//! includes the ID of the key used, so that keys can be rotated.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(secret(last = 4), serde = "redact")]
+//! pub struct CardNumber;
+//!
+//! #[braid(secret, serde = "error")]
+//! pub struct Password;
+//!
+//! #[braid(secret, serde = "expose")]
+//! pub struct ApiKey;
+//!
+//! let card: CardNumber = serde_json::from_str("\"4242424242424242\"").unwrap();
+//! assert_eq!("\"************4242\"", serde_json::to_string(&card).unwrap());
+//!
+//! let password = Password::from_static("hunter2");
+//! assert!(serde_json::to_string(&password).is_err());
+//!
+//! let key = ApiKey::from_static("hunter2");
+//! assert_eq!("\"hunter2\"", serde_json::to_string(&key).unwrap());
+//! ```
+//!
+//! ```compile_fail
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(secret, serde)]
+//! pub struct ApiKey;
+//! ```
+//!
 //! # Custom string types
 //!
 //! The `braid` macro can be used to define a custom string type that wraps types
@@ -820,10 +1437,12 @@
 //! * [`core::cmp::PartialOrd`] (unless `ord` is `omit`)
 //! * [`serde::Serialize`] (unless `serde` is `omit`)
 //! * [`serde::Deserialize`] (unless `serde` is `omit`)
+//!   * `locked::LockedString` implements both only with the `serde` feature
 //! * [`core::convert::From<&str>`]
 //! * [`core::convert::From<Box<str>>`]
 //! * [`core::convert::AsRef<str>`]
 //! * [`core::convert::Into<String>`]
+//! * `zeroize::Zeroize` (if `secret(zeroize)` is specified)
 //!
 //! [`serde::Serialize`]: https://docs.rs/serde/*/serde/trait.Serialize.html
 //! [`serde::Deserialize`]: https://docs.rs/serde/*/serde/trait.Deserialize.html
@@ -891,6 +1510,24 @@
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "generate")]
+pub mod generate;
+#[cfg(feature = "std")]
+pub mod load;
+#[cfg(feature = "mlock")]
+pub mod locked;
+pub mod redaction;
+#[cfg(feature = "scrub")]
+mod scrub;
+#[cfg(feature = "seal")]
+pub mod seal;
+mod validation;
+pub mod validators;
+#[cfg(any(feature = "generate", feature = "mlock", feature = "std"))]
+mod wipe;
 
 /// A validator that can verify a given input is valid given certain preconditions
 ///
@@ -911,6 +1548,35 @@ pub trait Validator {
     ///
     /// Returns an error if the string is invalid or not in normalized form.
     fn validate(raw: &str) -> Result<(), Self::Error>;
+
+    /// Validates a string, reporting every rule that it breaks rather than only the first
+    ///
+    /// The default implementation reports the single error from [`Validator::validate()`].
+    /// Validators generated by `validate(...)` and the [`validators::All`] and
+    /// [`validators::Map`] combinators report an error for each broken rule.
+    ///
+    /// Implementations must accept exactly the values accepted by [`Validator::validate()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns every error found if the string is invalid. The returned vector is never empty.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn validate_all(raw: &str) -> Result<(), ::alloc::vec::Vec<Self::Error>> {
+        Self::validate(raw).map_err(|error| ::alloc::vec![error])
+    }
+
+    /// Adds details about the braid that rejected a value to an error from this validator
+    ///
+    /// Braids call this with their type name, and whether they are a secret, before returning
+    /// an error produced by the validator or normalizer. The default implementation returns
+    /// the error unchanged. Validators producing a [`ValidationError`] should forward these
+    /// details to [`ValidationError::for_braid()`].
+    #[inline]
+    fn annotate_error(error: Self::Error, type_name: &'static str, secret: bool) -> Self::Error {
+        let _ = (type_name, secret);
+        error
+    }
 }
 
 /// A normalizer that can verify a given input is valid
@@ -922,7 +1588,141 @@ pub trait Normalizer: Validator {
     /// # Errors
     ///
     /// Returns an error if the string is invalid and cannot be normalized.
-    fn normalize(raw: &str) -> Result<::alloc::borrow::Cow<str>, Self::Error>;
+    fn normalize(raw: &str) -> Result<::alloc::borrow::Cow<'_, str>, Self::Error>;
+}
+
+/// A validator with additional rules that depend on a runtime context
+///
+/// Braids declared with `context_validator` gain constructors that take a reference to the
+/// context, such as `new_with()` and `from_str_with()`, which check the value with both
+/// [`Validator::validate()`] and [`ContextValidator::validate_with()`]. Constructors without a
+/// context only check the rules of [`Validator::validate()`].
+pub trait ContextValidator: Validator {
+    /// The context that values are checked against, such as loaded configuration
+    type Context: ?Sized;
+
+    /// Validates a string according to the rules of the given context
+    ///
+    /// Braids only call this for values that have already passed [`Validator::validate()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is invalid in the given context.
+    fn validate_with(context: &Self::Context, raw: &str) -> Result<(), Self::Error>;
+}
+
+/// A hook that is notified whenever the raw value of an audited secret braid is accessed
+///
+/// Braids declared with `secret(audit = "Hook")` call [`SecretAccessHook::on_access()`] on
+/// the given hook type from each generated accessor that exposes the raw value. Internal uses
+/// of the value, such as comparisons and redacted formatting, are not reported.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// use aliri_braid::{braid, SecretAccess, SecretAccessHook};
+///
+/// static ACCESSES: AtomicUsize = AtomicUsize::new(0);
+///
+/// pub struct CountAccesses;
+///
+/// impl SecretAccessHook for CountAccesses {
+///     fn on_access(type_name: &'static str, access: SecretAccess) {
+///         assert_eq!("ApiKeyRef", type_name);
+///         assert_eq!(SecretAccess::AsStr, access);
+///         ACCESSES.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+///
+/// #[braid(secret(audit = "CountAccesses"))]
+/// pub struct ApiKey;
+///
+/// let key = ApiKey::from_static("hunter2");
+/// assert_eq!("[redacted ApiKey]", key.to_string());
+/// assert_eq!(0, ACCESSES.load(Ordering::Relaxed));
+///
+/// assert_eq!("hunter2", key.as_str());
+/// assert_eq!(1, ACCESSES.load(Ordering::Relaxed));
+/// ```
+pub trait SecretAccessHook {
+    /// Called when the raw value of a secret of type `type_name` is accessed
+    fn on_access(type_name: &'static str, access: SecretAccess);
+}
+
+/// The kind of access reported to a [`SecretAccessHook`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SecretAccess {
+    /// The value was borrowed as a string slice, through `as_str()`, `expose_secret()`,
+    /// `with_exposed()`, `AsRef<str>`, or `Borrow<str>`
+    AsStr,
+    /// The value was unwrapped, through `take()`, `take_once()`, or a conversion into `String`
+    Take,
+    /// The value was revealed by formatting with the alternate flag or under
+    /// [`Policy::Reveal`](redaction::Policy::Reveal)
+    Format,
+    /// The value was serialized with `serde = "expose"`, or sealed with `serde = "sealed"`
+    ///
+    /// Serializing a redacted form with `serde = "redact"` is not reported.
+    Serialize,
+    /// The value was copied through `duplicate()`, generated by `clone = "explicit"`
+    Duplicate,
+    /// The value was modified through `edit()`
+    Edit,
+}
+
+/// The error returned when taking the value of a `secret(once)` braid that has already
+/// been taken
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AlreadyTaken {
+    type_name: &'static str,
+}
+
+impl AlreadyTaken {
+    /// Constructs a new error for a value of the named type
+    #[inline]
+    pub const fn new(type_name: &'static str) -> Self {
+        Self { type_name }
+    }
+
+    /// The name of the type whose value has already been taken
+    #[inline]
+    pub const fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+impl core::fmt::Display for AlreadyTaken {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "the value of `{}` has already been taken",
+            self.type_name
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AlreadyTaken {}
+
+/// Compares two strings for equality in constant time
+///
+/// The time taken depends on the lengths of the inputs, but not on their contents, so
+/// comparing against a secret value does not reveal how much of it matched. This is used
+/// by the equality implementations generated for `secret` braids.
+///
+/// # Example
+///
+/// ```
+/// assert!(aliri_braid::constant_time_eq("hunter2", "hunter2"));
+/// assert!(!aliri_braid::constant_time_eq("hunter2", "hunter3"));
+/// assert!(!aliri_braid::constant_time_eq("hunter2", "hunter"));
+/// ```
+#[inline]
+pub fn constant_time_eq(a: &str, b: &str) -> bool {
+    subtle::ConstantTimeEq::ct_eq(a.as_bytes(), b.as_bytes()).into()
 }
 
 /// Utility macro for easily defining `From<Infallible>` for a given type.
@@ -955,3 +1755,9 @@ macro_rules! from_infallible {
 }
 
 pub use aliri_braid_impl::{braid, braid_ref};
+#[cfg(feature = "scrub")]
+pub use scrub::Scrubber;
+pub use validation::{RenderedError, ValidationError};
+/// Re-export of the [`zeroize`] crate, used by braids declared with `secret(zeroize)`
+#[cfg(feature = "zeroize")]
+pub use zeroize;
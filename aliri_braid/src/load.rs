@@ -0,0 +1,248 @@
+//! Loading secrets from environment variables and files
+//!
+//! These functions back the `from_env()` and `from_file()` constructors generated for
+//! braids declared with `secret(load)`. Any buffers used to hold the raw value while it is
+//! read and validated are wiped before being freed, and errors never include the value.
+//!
+//! ```
+//! use aliri_braid::braid;
+//!
+//! #[braid(secret(load))]
+//! pub struct ApiKey;
+//!
+//! # std::env::set_var("EXAMPLE_API_KEY", "hunter2");
+//! let key = ApiKey::from_env("EXAMPLE_API_KEY").unwrap();
+//! # assert_eq!("hunter2", key.as_str());
+//! ```
+
+use std::{
+    convert::TryFrom,
+    env, error, fmt,
+    fs::File,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    string::String,
+};
+
+use crate::wipe::Scratch;
+
+/// Loads a value from the environment variable `var`
+///
+/// The value is passed to `parse` as read, without trimming.
+pub fn from_env<T, E>(
+    type_name: &'static str,
+    var: &str,
+    parse: impl FnOnce(&str) -> Result<T, E>,
+) -> Result<T, LoadError<E>> {
+    let origin = || Origin::Env(var.into());
+
+    let raw = env::var_os(var)
+        .ok_or_else(|| LoadError::new(type_name, origin(), LoadErrorKind::NotPresent))?;
+
+    #[cfg(unix)]
+    let raw = Scratch(std::os::unix::ffi::OsStringExt::into_vec(raw));
+    #[cfg(not(unix))]
+    let raw = Scratch(
+        raw.into_string()
+            .map_err(|_| LoadError::new(type_name, origin(), LoadErrorKind::NotUnicode))?
+            .into_bytes(),
+    );
+
+    raw.parse(parse)
+        .map_err(|kind| LoadError::new(type_name, origin(), kind))
+}
+
+/// Loads a value from the file at `path`
+///
+/// A single trailing line ending, `\n` or `\r\n`, is removed before the value is passed
+/// to `parse`.
+pub fn from_file<T, E>(
+    type_name: &'static str,
+    path: &Path,
+    parse: impl FnOnce(&str) -> Result<T, E>,
+) -> Result<T, LoadError<E>> {
+    let origin = || Origin::File(path.into());
+
+    let mut raw =
+        read_file(path).map_err(|e| LoadError::new(type_name, origin(), LoadErrorKind::Io(e)))?;
+    raw.trim_line_ending();
+
+    raw.parse(parse)
+        .map_err(|kind| LoadError::new(type_name, origin(), kind))
+}
+
+fn read_file(path: &Path) -> io::Result<Scratch> {
+    let mut file = File::open(path)?;
+    let hint = file
+        .metadata()
+        .ok()
+        .and_then(|m| usize::try_from(m.len()).ok())
+        .unwrap_or(0);
+
+    // The buffer is grown manually so that no unwiped copies are left behind
+    let mut buf = Scratch(std::vec![0; hint.saturating_add(1).max(64)]);
+    let mut filled = 0;
+    loop {
+        if filled == buf.0.len() {
+            let mut bigger = Scratch(std::vec![0; buf.0.len() * 2]);
+            bigger.0[..filled].copy_from_slice(&buf.0);
+            buf = bigger;
+        }
+
+        match file.read(&mut buf.0[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    buf.0.truncate(filled);
+    Ok(buf)
+}
+
+impl Scratch {
+    fn trim_line_ending(&mut self) {
+        if self.0.last() == Some(&b'\n') {
+            self.0.pop();
+            if self.0.last() == Some(&b'\r') {
+                self.0.pop();
+            }
+        }
+    }
+
+    fn parse<T, E>(&self, parse: impl FnOnce(&str) -> Result<T, E>) -> Result<T, LoadErrorKind<E>> {
+        let raw = core::str::from_utf8(&self.0).map_err(|_| LoadErrorKind::NotUnicode)?;
+        parse(raw).map_err(LoadErrorKind::Invalid)
+    }
+}
+
+/// Where a value was being loaded from
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Origin {
+    /// An environment variable with the given name
+    Env(String),
+
+    /// A file at the given path
+    File(PathBuf),
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Env(var) => write!(f, "environment variable `{var}`"),
+            Self::File(path) => write!(f, "file `{}`", path.display()),
+        }
+    }
+}
+
+/// The reason that a value could not be loaded
+#[non_exhaustive]
+pub enum LoadErrorKind<E> {
+    /// The environment variable is not set
+    NotPresent,
+
+    /// The value is not valid UTF-8
+    NotUnicode,
+
+    /// The file could not be read
+    Io(io::Error),
+
+    /// The value was rejected by the braid's validator or normalizer
+    ///
+    /// The validator's error is not included in the `Debug` or `Display` output of
+    /// [`LoadError`], as it may include the rejected value.
+    Invalid(E),
+}
+
+impl<E> fmt::Debug for LoadErrorKind<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotPresent => f.write_str("NotPresent"),
+            Self::NotUnicode => f.write_str("NotUnicode"),
+            Self::Io(e) => f.debug_tuple("Io").field(e).finish(),
+            Self::Invalid(_) => f.write_str("Invalid(..)"),
+        }
+    }
+}
+
+impl<E> fmt::Display for LoadErrorKind<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotPresent => f.write_str("not present"),
+            Self::NotUnicode => f.write_str("not valid UTF-8"),
+            Self::Io(e) => fmt::Display::fmt(e, f),
+            Self::Invalid(_) => f.write_str("invalid value"),
+        }
+    }
+}
+
+/// An error encountered while loading a value from the environment or a file
+///
+/// Neither the `Debug` nor the `Display` output of this error include the value being
+/// loaded.
+pub struct LoadError<E> {
+    type_name: &'static str,
+    origin: Origin,
+    kind: LoadErrorKind<E>,
+}
+
+impl<E> LoadError<E> {
+    fn new(type_name: &'static str, origin: Origin, kind: LoadErrorKind<E>) -> Self {
+        Self {
+            type_name,
+            origin,
+            kind,
+        }
+    }
+
+    /// The name of the type being loaded
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Where the value was being loaded from
+    pub fn origin(&self) -> &Origin {
+        &self.origin
+    }
+
+    /// The reason that the value could not be loaded
+    pub fn kind(&self) -> &LoadErrorKind<E> {
+        &self.kind
+    }
+
+    /// Unwraps the reason that the value could not be loaded
+    pub fn into_kind(self) -> LoadErrorKind<E> {
+        self.kind
+    }
+}
+
+impl<E> fmt::Debug for LoadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LoadError")
+            .field("type_name", &self.type_name)
+            .field("origin", &self.origin)
+            .field("kind", &self.kind)
+            .finish()
+    }
+}
+
+impl<E> fmt::Display for LoadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "failed to load `{}` from {}: {}",
+            self.type_name, self.origin, self.kind
+        )
+    }
+}
+
+impl<E> error::Error for LoadError<E> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match &self.kind {
+            LoadErrorKind::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
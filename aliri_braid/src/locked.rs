@@ -0,0 +1,314 @@
+//! Locked-memory storage for high-value secrets
+//!
+//! [`LockedString`] keeps its value in memory that is locked into RAM, so that it is never
+//! written out to swap, and wipes that memory before releasing it. It can be used as the
+//! [custom string type](crate#custom-string-types) of a braid:
+//!
+//! ```
+//! use aliri_braid::{braid, locked::LockedString};
+//!
+//! #[braid(secret)]
+//! pub struct SigningKey(LockedString);
+//!
+//! let key = SigningKey::from_static("hunter2");
+//! assert_eq!("hunter2", key.as_str());
+//! ```
+//!
+//! Only the owned form of such a braid is locked. Values converted into other forms, such
+//! as a `Box` of the borrowed form or a `String`, are ordinary copies.
+//!
+//! `LockedString` implements `serde::Serialize` and `serde::Deserialize` when the `serde`
+//! feature is enabled, as required for braids that implement them. Without that feature,
+//! braids wrapping it cannot be declared with `serde`.
+
+use alloc::{
+    alloc::{alloc_zeroed, dealloc, handle_alloc_error, Layout},
+    boxed::Box,
+    string::String,
+};
+use core::{
+    fmt, hash,
+    ptr::NonNull,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static LOCK_FAILURES: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns the number of times that memory for a [`LockedString`] could not be locked
+/// in this process
+///
+/// A failure to lock memory does not prevent a [`LockedString`] from being created, so this
+/// can be monitored to detect when secrets may be written out to swap, such as when the
+/// process has reached its limit on locked memory (`RLIMIT_MEMLOCK` on Unix).
+pub fn lock_failures() -> usize {
+    LOCK_FAILURES.load(Ordering::Relaxed)
+}
+
+/// A string stored in locked memory that is wiped when dropped
+///
+/// Each value is given its own page-aligned allocation, which is locked with `mlock` so
+/// that it cannot be swapped to disk. If locking fails, or is not supported on the current
+/// platform, the value is still stored and wiped on drop, but [`is_locked()`] returns
+/// `false` and the failure is counted by [`lock_failures()`].
+///
+/// As each value occupies at least one page of memory, this type is best reserved for a
+/// small number of high-value secrets, such as signing keys.
+///
+/// The value is never included in the `Debug` output, and `Display` is not implemented,
+/// so a braid using this type should be declared `secret`. Equality comparisons are made
+/// in constant time.
+///
+/// [`is_locked()`]: Self::is_locked
+pub struct LockedString {
+    ptr: NonNull<u8>,
+    len: usize,
+    layout: Layout,
+    locked: bool,
+}
+
+#[allow(unsafe_code)]
+mod sync_safety {
+    /// SAFETY: `LockedString` uniquely owns its allocation, like `Box<str>`, and provides
+    /// no interior mutability
+    unsafe impl Send for super::LockedString {}
+
+    /// SAFETY: See above
+    unsafe impl Sync for super::LockedString {}
+}
+
+impl LockedString {
+    /// Copies `value` into newly allocated locked memory
+    ///
+    /// The memory is locked before the value is copied into it.
+    #[allow(unsafe_code)]
+    pub fn new(value: &str) -> Self {
+        if value.is_empty() {
+            return Self {
+                ptr: NonNull::dangling(),
+                len: 0,
+                layout: Layout::new::<()>(),
+                locked: false,
+            };
+        }
+
+        let page_size = page_size();
+        // `usize::div_ceil` is not available on older compilers
+        #[allow(clippy::manual_div_ceil)]
+        let size = (value.len() + page_size - 1) / page_size * page_size;
+        let layout = Layout::from_size_align(size, page_size)
+            .unwrap_or_else(|_| panic!("locked string of {} bytes is too large", value.len()));
+
+        // SAFETY: `layout` has a non-zero size, as `value` is not empty
+        let ptr = NonNull::new(unsafe { alloc_zeroed(layout) })
+            .unwrap_or_else(|| handle_alloc_error(layout));
+
+        let locked = lock(ptr, size);
+        if !locked {
+            LOCK_FAILURES.fetch_add(1, Ordering::Relaxed);
+        }
+
+        // SAFETY: The allocation is at least `value.len()` bytes and cannot overlap `value`
+        unsafe {
+            core::ptr::copy_nonoverlapping(value.as_ptr(), ptr.as_ptr(), value.len());
+        }
+
+        Self {
+            ptr,
+            len: value.len(),
+            layout,
+            locked,
+        }
+    }
+
+    /// Returns whether the memory holding the value is locked
+    ///
+    /// This is `false` if locking failed, in which case the value may be swapped to disk, or if
+    /// the value is empty, in which case there is no memory to lock.
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    /// Provides access to the value as a string slice
+    #[allow(unsafe_code)]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: The first `len` bytes of the allocation were copied from a `str`, and
+        // are only ever overwritten with zeros, which are valid UTF-8
+        unsafe {
+            core::str::from_utf8_unchecked(core::slice::from_raw_parts(self.ptr.as_ptr(), self.len))
+        }
+    }
+
+    fn wipe(&mut self) {
+        wipe(self.ptr, self.len);
+    }
+}
+
+impl Drop for LockedString {
+    #[allow(unsafe_code)]
+    fn drop(&mut self) {
+        if self.len == 0 {
+            return;
+        }
+
+        self.wipe();
+        if self.locked {
+            unlock(self.ptr, self.layout.size());
+        }
+
+        // SAFETY: `ptr` was allocated with `layout` in `new`
+        unsafe { dealloc(self.ptr.as_ptr(), self.layout) }
+    }
+}
+
+impl Clone for LockedString {
+    fn clone(&self) -> Self {
+        Self::new(self.as_str())
+    }
+}
+
+impl fmt::Debug for LockedString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LockedString")
+            .field("len", &self.len)
+            .field("locked", &self.locked)
+            .finish()
+    }
+}
+
+impl PartialEq for LockedString {
+    fn eq(&self, other: &Self) -> bool {
+        crate::constant_time_eq(self.as_str(), other.as_str())
+    }
+}
+
+impl Eq for LockedString {}
+
+impl hash::Hash for LockedString {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl AsRef<str> for LockedString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<&str> for LockedString {
+    fn from(s: &str) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<Box<str>> for LockedString {
+    fn from(s: Box<str>) -> Self {
+        let locked = Self::new(&s);
+        let mut bytes = s.into_boxed_bytes();
+        crate::wipe::wipe(&mut bytes);
+        locked
+    }
+}
+
+impl From<String> for LockedString {
+    fn from(s: String) -> Self {
+        let locked = Self::new(&s);
+        let mut bytes = s.into_bytes();
+        crate::wipe::wipe(&mut bytes);
+        locked
+    }
+}
+
+impl From<LockedString> for String {
+    /// Copies the value into an ordinary, unlocked `String`
+    fn from(s: LockedString) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for LockedString {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LockedString {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = LockedString;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(LockedString::new(v))
+            }
+
+            fn visit_string<E: serde::de::Error>(self, v: String) -> Result<Self::Value, E> {
+                Ok(LockedString::from(v))
+            }
+        }
+
+        deserializer.deserialize_string(Visitor)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for LockedString {
+    fn zeroize(&mut self) {
+        self.wipe();
+    }
+}
+
+#[allow(unsafe_code)]
+fn wipe(ptr: NonNull<u8>, len: usize) {
+    // SAFETY: Callers pass the pointer and length of a live `LockedString`, which uniquely
+    // owns its allocation
+    crate::wipe::wipe(unsafe { core::slice::from_raw_parts_mut(ptr.as_ptr(), len) });
+}
+
+#[cfg(unix)]
+fn page_size() -> usize {
+    #[allow(unsafe_code)]
+    // SAFETY: `sysconf` has no preconditions
+    let size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    <usize as core::convert::TryFrom<_>>::try_from(size)
+        .ok()
+        .filter(|s| s.is_power_of_two())
+        .unwrap_or(4096)
+}
+
+#[cfg(not(unix))]
+fn page_size() -> usize {
+    4096
+}
+
+#[cfg(unix)]
+#[allow(unsafe_code)]
+fn lock(ptr: NonNull<u8>, size: usize) -> bool {
+    // SAFETY: The range is a live allocation owned by the caller
+    unsafe { libc::mlock(ptr.as_ptr().cast(), size) == 0 }
+}
+
+#[cfg(not(unix))]
+fn lock(_ptr: NonNull<u8>, _size: usize) -> bool {
+    false
+}
+
+#[cfg(unix)]
+#[allow(unsafe_code)]
+fn unlock(ptr: NonNull<u8>, size: usize) {
+    // SAFETY: The range is a live allocation owned by the caller, which was locked
+    // with `mlock`, and occupies whole pages that are not shared with any other value
+    unsafe {
+        libc::munlock(ptr.as_ptr().cast(), size);
+    }
+}
+
+#[cfg(not(unix))]
+fn unlock(_ptr: NonNull<u8>, _size: usize) {}
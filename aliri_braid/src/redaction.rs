@@ -0,0 +1,551 @@
+//! Helpers for redacting secret values when formatting
+//!
+//! These are used by the `Debug` and `Display` implementations generated for `secret`
+//! braids, but can also be used to implement the same redaction strategies by hand.
+//!
+//! This module also holds the process-wide key used to compute [`Fingerprint`]s of secret
+//! values, which can be set once with [`set_fingerprint_key()`], and the process-wide
+//! [`Policy`] that controls whether secrets are redacted at all.
+//!
+//! Both are stored in atomics. On targets without 32-bit atomics, such as `thumbv6m`, no
+//! fingerprint key can be set, and fingerprints are never computed. On targets without
+//! atomic compare-and-swap, the process-wide policy is fixed to [`Policy::Redact`].
+
+use core::fmt;
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::AtomicU8;
+#[cfg(any(target_has_atomic = "8", target_has_atomic = "32"))]
+use core::sync::atomic::Ordering;
+#[cfg(target_has_atomic = "32")]
+use core::{hash::Hasher, sync::atomic::AtomicU32};
+
+#[cfg(target_has_atomic = "32")]
+use siphasher::sip::SipHasher24;
+
+/// The character used in place of masked characters
+pub const MASK_CHAR: char = '*';
+
+/// Writes `value` with all but the last `visible` characters masked
+///
+/// If `value` has no more than `visible` characters, then every character is masked,
+/// so that short values are never revealed in full.
+///
+/// # Example
+///
+/// ```
+/// use std::fmt;
+///
+/// struct CardNumber(&'static str);
+///
+/// impl fmt::Display for CardNumber {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         aliri_braid::redaction::write_masked(f, self.0, 4)
+///     }
+/// }
+///
+/// assert_eq!("************4242", CardNumber("4242424242424242").to_string());
+/// assert_eq!("****", CardNumber("4242").to_string());
+/// ```
+pub fn write_masked(f: &mut fmt::Formatter<'_>, value: &str, visible: usize) -> fmt::Result {
+    let len = value.chars().count();
+    let masked = if len > visible { len - visible } else { len };
+
+    for _ in 0..masked {
+        fmt::Write::write_char(f, MASK_CHAR)?;
+    }
+
+    match value.char_indices().nth(masked) {
+        Some((idx, _)) => f.write_str(&value[idx..]),
+        None => Ok(()),
+    }
+}
+
+/// Writes a redaction message noting only the number of characters in `value`
+///
+/// # Example
+///
+/// ```
+/// use std::fmt;
+///
+/// struct Token(&'static str);
+///
+/// impl fmt::Display for Token {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         aliri_braid::redaction::write_length_only(f, self.0)
+///     }
+/// }
+///
+/// assert_eq!("[redacted, 7 chars]", Token("hunter2").to_string());
+/// assert_eq!("[redacted, 1 char]", Token("x").to_string());
+/// ```
+pub fn write_length_only(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    match value.chars().count() {
+        1 => f.write_str("[redacted, 1 char]"),
+        len => write!(f, "[redacted, {len} chars]"),
+    }
+}
+
+#[cfg(target_has_atomic = "32")]
+const KEY_UNSET: u32 = 0;
+#[cfg(target_has_atomic = "32")]
+const KEY_SETTING: u32 = 1;
+#[cfg(target_has_atomic = "32")]
+const KEY_SET: u32 = 2;
+
+#[cfg(target_has_atomic = "32")]
+static FINGERPRINT_KEY_STATE: AtomicU32 = AtomicU32::new(KEY_UNSET);
+/// The key, split into 32-bit words so that it can be stored without 64-bit atomics
+#[cfg(target_has_atomic = "32")]
+static FINGERPRINT_KEY: [AtomicU32; 4] = [
+    AtomicU32::new(0),
+    AtomicU32::new(0),
+    AtomicU32::new(0),
+    AtomicU32::new(0),
+];
+
+/// Sets the process-wide key used to compute [`Fingerprint`]s
+///
+/// The key should be generated randomly at startup and kept secret. Without it, a
+/// fingerprint cannot be used to recover or confirm a guess of the value it was computed
+/// from, even when that value is short. Until a key has been set, no fingerprints are
+/// computed and braids declared with `secret(fingerprint)` are redacted without one.
+///
+/// # Errors
+///
+/// Returns an error if a key has already been set. The key can only be set once, so that
+/// fingerprints remain comparable for the lifetime of the process.
+///
+/// Only available on targets with 32-bit atomics.
+///
+/// # Example
+///
+/// ```
+/// use aliri_braid::redaction;
+///
+/// let key = [0x5a; 16]; // use a randomly generated key in practice
+/// redaction::set_fingerprint_key(key).unwrap();
+/// assert!(redaction::set_fingerprint_key(key).is_err());
+/// ```
+#[cfg(target_has_atomic = "32")]
+pub fn set_fingerprint_key(key: [u8; 16]) -> Result<(), FingerprintKeyAlreadySet> {
+    FINGERPRINT_KEY_STATE
+        .compare_exchange(KEY_UNSET, KEY_SETTING, Ordering::Acquire, Ordering::Relaxed)
+        .map_err(|_| FingerprintKeyAlreadySet)?;
+
+    for (word, chunk) in FINGERPRINT_KEY.iter().zip(key.chunks_exact(4)) {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(chunk);
+        word.store(u32::from_le_bytes(bytes), Ordering::Relaxed);
+    }
+    FINGERPRINT_KEY_STATE.store(KEY_SET, Ordering::Release);
+
+    Ok(())
+}
+
+/// The error returned when attempting to set the fingerprint key more than once
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FingerprintKeyAlreadySet;
+
+impl fmt::Display for FingerprintKeyAlreadySet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("fingerprint key has already been set")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FingerprintKeyAlreadySet {}
+
+/// A short keyed hash of a secret value
+///
+/// Fingerprints make it possible to tell whether two redacted values are the same, such as
+/// when correlating log lines, without revealing the values themselves. They are computed
+/// with SipHash-2-4 using the key provided to [`set_fingerprint_key()`], and are formatted
+/// as eight hexadecimal digits.
+///
+/// Being short, distinct values may share a fingerprint, so fingerprints must not be used
+/// to compare secrets for equality.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Fingerprint(u32);
+
+impl Fingerprint {
+    /// Computes the fingerprint of `value`
+    ///
+    /// Returns `None` if no key has been set with [`set_fingerprint_key()`].
+    #[cfg(target_has_atomic = "32")]
+    pub fn of(value: &str) -> Option<Self> {
+        if FINGERPRINT_KEY_STATE.load(Ordering::Acquire) != KEY_SET {
+            return None;
+        }
+
+        let word = |i: usize| u64::from(FINGERPRINT_KEY[i].load(Ordering::Relaxed));
+        let mut hasher =
+            SipHasher24::new_with_keys(word(0) | (word(1) << 32), word(2) | (word(3) << 32));
+        hasher.write(value.as_bytes());
+        Some(Self(hasher.finish() as u32))
+    }
+
+    /// Computes the fingerprint of `value`
+    ///
+    /// Always returns `None`, as no key can be set on targets without 32-bit atomics.
+    #[cfg(not(target_has_atomic = "32"))]
+    pub fn of(value: &str) -> Option<Self> {
+        let _ = value;
+        None
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:08x}", self.0)
+    }
+}
+
+/// Writes a redaction message for a value of type `type_name`, including the
+/// [`Fingerprint`] of `value` if a key has been set
+///
+/// # Example
+///
+/// ```
+/// use std::fmt;
+///
+/// struct ApiKey(&'static str);
+///
+/// impl fmt::Debug for ApiKey {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         aliri_braid::redaction::write_fingerprinted(f, "ApiKey", self.0)
+///     }
+/// }
+///
+/// assert_eq!("[redacted ApiKey]", format!("{:?}", ApiKey("hunter2")));
+///
+/// aliri_braid::redaction::set_fingerprint_key([0x5a; 16]).unwrap();
+/// let debug = format!("{:?}", ApiKey("hunter2"));
+/// assert!(debug.starts_with("[redacted ApiKey#"));
+/// assert_eq!(debug, format!("{:?}", ApiKey("hunter2")));
+/// assert_ne!(debug, format!("{:?}", ApiKey("hunter3")));
+/// ```
+pub fn write_fingerprinted(
+    f: &mut fmt::Formatter<'_>,
+    type_name: &str,
+    value: &str,
+) -> fmt::Result {
+    match Fingerprint::of(value) {
+        Some(fingerprint) => write!(f, "[redacted {type_name}#{fingerprint}]"),
+        None => write!(f, "[redacted {type_name}]"),
+    }
+}
+
+/// Writes the redaction message of a secret without consulting the runtime [`Policy`]
+///
+/// Implemented for both forms of every `secret` braid, following its redaction strategy. The
+/// message never includes a [`Fingerprint`], and writing it is not reported to the audit hook
+/// of the braid, so it is suitable for registering replacements with a `Scrubber`.
+pub trait StaticRedaction {
+    /// Writes the redaction message to `f`
+    fn fmt_static_redaction(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// Formats a secret with its [`StaticRedaction`]
+///
+/// # Example
+///
+/// ```
+/// use aliri_braid::{braid, redaction::{self, Policy, StaticallyRedacted}};
+///
+/// #[braid(secret(last = 2))]
+/// pub struct ApiKey;
+///
+/// let key = ApiKey::from_static("hunter2");
+/// redaction::with_policy(Policy::Reveal, || {
+///     assert_eq!("*****r2", StaticallyRedacted(&key).to_string());
+/// })
+/// .unwrap();
+/// ```
+pub struct StaticallyRedacted<'a, T: ?Sized>(pub &'a T);
+
+impl<T: ?Sized + StaticRedaction> fmt::Display for StaticallyRedacted<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_static_redaction(f)
+    }
+}
+
+impl<T: ?Sized + StaticRedaction> fmt::Debug for StaticallyRedacted<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_static_redaction(f)
+    }
+}
+
+/// A process-wide policy controlling how `secret` braids are formatted
+///
+/// The policy is consulted by the `Debug` and `Display` implementations generated for all
+/// `secret` braids, and can be changed at runtime with [`set_policy()`]. It does not affect
+/// serialization.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Policy {
+    /// Secrets are redacted according to the strategy configured for each type
+    ///
+    /// This is the default policy.
+    #[default]
+    Redact,
+    /// Secrets are formatted as if they were not secret, revealing their values
+    ///
+    /// The values of `strict` secrets are still redacted, as they can only be exposed
+    /// explicitly. This policy cannot be set once the policy has been locked with
+    /// [`lock_policy()`].
+    Reveal,
+    /// Secrets are redacted with a [`Fingerprint`] of their values, regardless of the
+    /// strategy configured for each type
+    Fingerprint,
+}
+
+#[cfg(target_has_atomic = "8")]
+impl Policy {
+    const fn to_bits(self) -> u8 {
+        match self {
+            Self::Redact => 0,
+            Self::Reveal => 1,
+            Self::Fingerprint => 2,
+        }
+    }
+
+    const fn from_bits(bits: u8) -> Self {
+        match bits & !POLICY_LOCKED {
+            1 => Self::Reveal,
+            2 => Self::Fingerprint,
+            _ => Self::Redact,
+        }
+    }
+}
+
+#[cfg(target_has_atomic = "8")]
+const POLICY_LOCKED: u8 = 0x80;
+
+#[cfg(target_has_atomic = "8")]
+static POLICY: AtomicU8 = AtomicU8::new(0);
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static POLICY_OVERRIDE: core::cell::Cell<Option<Policy>> = const { core::cell::Cell::new(None) };
+}
+
+/// Returns the redaction policy currently in effect
+///
+/// This is the policy set by the innermost [`with_policy()`] call on the current thread, if
+/// any, or else the process-wide policy set by [`set_policy()`]. Once the policy has been
+/// locked, an override to [`Policy::Reveal`] that began before the lock is treated as
+/// [`Policy::Redact`].
+pub fn policy() -> Policy {
+    #[cfg(feature = "std")]
+    {
+        match POLICY_OVERRIDE.with(core::cell::Cell::get) {
+            Some(Policy::Reveal) if is_policy_locked() => return Policy::Redact,
+            Some(policy) => return policy,
+            None => {}
+        }
+    }
+
+    #[cfg(target_has_atomic = "8")]
+    {
+        Policy::from_bits(POLICY.load(Ordering::Relaxed))
+    }
+    #[cfg(not(target_has_atomic = "8"))]
+    {
+        Policy::Redact
+    }
+}
+
+/// Sets the process-wide redaction policy
+///
+/// # Errors
+///
+/// Returns an error if `policy` is [`Policy::Reveal`] and the policy has been locked
+/// with [`lock_policy()`].
+///
+/// Only available on targets with atomic compare-and-swap.
+///
+/// # Example
+///
+/// ```
+/// use aliri_braid::{braid, redaction::{self, Policy}};
+///
+/// #[braid(secret)]
+/// pub struct ApiKey;
+///
+/// let key = ApiKey::from_static("hunter2");
+/// assert_eq!("[redacted ApiKey]", key.to_string());
+///
+/// redaction::set_policy(Policy::Reveal).unwrap();
+/// assert_eq!("hunter2", key.to_string());
+///
+/// redaction::lock_policy();
+/// assert_eq!(Policy::Redact, redaction::policy());
+/// assert!(redaction::set_policy(Policy::Reveal).is_err());
+/// assert_eq!("[redacted ApiKey]", key.to_string());
+/// ```
+#[cfg(target_has_atomic = "8")]
+pub fn set_policy(policy: Policy) -> Result<(), PolicyLocked> {
+    POLICY
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |bits| {
+            let locked = bits & POLICY_LOCKED;
+            if locked != 0 && policy == Policy::Reveal {
+                None
+            } else {
+                Some(locked | policy.to_bits())
+            }
+        })
+        .map(|_| ())
+        .map_err(|_| PolicyLocked)
+}
+
+/// Locks the redaction policy, so that it can no longer be set to [`Policy::Reveal`]
+///
+/// If the current process-wide policy is [`Policy::Reveal`], it is replaced with
+/// [`Policy::Redact`], as are any overrides to [`Policy::Reveal`] made by [`with_policy()`]. The
+/// policy may still be changed between [`Policy::Redact`] and [`Policy::Fingerprint`] once locked.
+/// Production builds should call this at startup, so that values cannot be revealed by a stray call
+/// to [`set_policy()`].
+///
+/// Locking cannot be undone. Only available on targets with atomic compare-and-swap.
+#[cfg(target_has_atomic = "8")]
+pub fn lock_policy() {
+    let _ = POLICY.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |bits| {
+        let policy = match Policy::from_bits(bits) {
+            Policy::Reveal => Policy::Redact,
+            policy => policy,
+        };
+        Some(POLICY_LOCKED | policy.to_bits())
+    });
+}
+
+/// Returns whether the redaction policy has been locked with [`lock_policy()`]
+///
+/// Always `false` on targets without atomic compare-and-swap, where the process-wide policy
+/// cannot be changed.
+pub fn is_policy_locked() -> bool {
+    #[cfg(target_has_atomic = "8")]
+    {
+        POLICY.load(Ordering::Relaxed) & POLICY_LOCKED != 0
+    }
+    #[cfg(not(target_has_atomic = "8"))]
+    {
+        false
+    }
+}
+
+/// Overrides the redaction policy on the current thread while running `f`
+///
+/// The override only affects the current thread, which makes it suitable for use in tests
+/// that are run in parallel. The previous policy is restored when `f` returns or panics.
+///
+/// # Errors
+///
+/// Returns an error without running `f` if `policy` is [`Policy::Reveal`] and the policy
+/// has been locked with [`lock_policy()`].
+///
+/// # Example
+///
+/// ```
+/// use aliri_braid::{braid, redaction::{self, Policy}};
+///
+/// #[braid(secret)]
+/// pub struct ApiKey;
+///
+/// let key = ApiKey::from_static("hunter2");
+/// let revealed = redaction::with_policy(Policy::Reveal, || key.to_string()).unwrap();
+/// assert_eq!("hunter2", revealed);
+/// assert_eq!("[redacted ApiKey]", key.to_string());
+/// ```
+#[cfg(feature = "std")]
+pub fn with_policy<R>(policy: Policy, f: impl FnOnce() -> R) -> Result<R, PolicyLocked> {
+    struct Restore(Option<Policy>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            POLICY_OVERRIDE.with(|p| p.set(self.0));
+        }
+    }
+
+    if policy == Policy::Reveal && is_policy_locked() {
+        return Err(PolicyLocked);
+    }
+
+    let _restore = Restore(POLICY_OVERRIDE.with(|p| p.replace(Some(policy))));
+    Ok(f())
+}
+
+/// The error returned when attempting to reveal secrets after the redaction policy
+/// has been locked
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PolicyLocked;
+
+impl fmt::Display for PolicyLocked {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("redaction policy is locked and cannot be set to reveal secrets")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PolicyLocked {}
+
+/// The error produced when a `secret` braid rejects a value
+///
+/// Errors from validators and normalizers often describe the rejected input, which for
+/// a secret braid is the secret itself. This wraps such an error so that it is never
+/// formatted, while keeping it available for inspection with [`get_ref()`] and
+/// [`into_inner()`]. The `source()` of the wrapped error is preserved.
+///
+/// [`get_ref()`]: Self::get_ref
+/// [`into_inner()`]: Self::into_inner
+#[derive(Clone, PartialEq, Eq)]
+pub struct RedactedError<E> {
+    type_name: &'static str,
+    error: E,
+}
+
+impl<E> RedactedError<E> {
+    /// Wraps an error produced while checking a value of the named type
+    pub const fn new(type_name: &'static str, error: E) -> Self {
+        Self { type_name, error }
+    }
+
+    /// The name of the type that rejected the value
+    pub const fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The wrapped error
+    ///
+    /// Take care not to format this error, as it may include the rejected value.
+    pub const fn get_ref(&self) -> &E {
+        &self.error
+    }
+
+    /// Unwraps the error
+    ///
+    /// Take care not to format this error, as it may include the rejected value.
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E> fmt::Debug for RedactedError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RedactedError")
+            .field("type_name", &self.type_name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<E> fmt::Display for RedactedError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid value for `{}`", self.type_name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error> std::error::Error for RedactedError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
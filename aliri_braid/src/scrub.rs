@@ -0,0 +1,249 @@
+use std::{
+    borrow::Cow,
+    boxed::Box,
+    collections::BTreeMap,
+    fmt,
+    string::{String, ToString},
+    sync::{Arc, Mutex, MutexGuard},
+    vec::Vec,
+};
+
+use aho_corasick::{AhoCorasick, MatchKind};
+
+use crate::redaction::{StaticRedaction, StaticallyRedacted};
+
+/// A registry of secret values to be removed from arbitrary text
+///
+/// Text produced outside of the type system, such as error messages from third-party
+/// libraries, may contain secrets that would otherwise be redacted. A scrubber replaces
+/// every registered secret found in such text with the redaction message for that secret.
+///
+/// Secrets can be registered explicitly with [`register()`] or [`insert()`], or
+/// automatically by declaring a braid with `secret(scrub)`, in which case each owned value
+/// is registered with the [`global()`] scrubber when it is constructed and removed when it
+/// is dropped. Registrations are counted, so a secret remains registered until it has been
+/// removed as many times as it was inserted.
+///
+/// ```
+/// use aliri_braid::{braid, Scrubber};
+///
+/// #[braid(secret(scrub))]
+/// pub struct ApiKey;
+///
+/// let key = ApiKey::from_static("hunter2");
+/// let message = "GET https://example.com/?key=hunter2 failed";
+/// assert_eq!(
+///     "GET https://example.com/?key=[redacted ApiKey] failed",
+///     Scrubber::global().scrub(message),
+/// );
+///
+/// drop(key);
+/// assert_eq!(message, Scrubber::global().scrub(message));
+/// ```
+///
+/// Secrets are found with a multi-pattern search, so the cost of scrubbing grows with
+/// the length of the text rather than with the number of registered secrets. The search
+/// is rebuilt on the next call to [`scrub()`] after the registered secrets change.
+///
+/// Registered secrets are copied into the scrubber. Those copies are wiped when the
+/// secret is removed, but the search structure may retain copies of its own until it is
+/// rebuilt.
+///
+/// [`register()`]: Self::register
+/// [`insert()`]: Self::insert
+/// [`global()`]: Self::global
+/// [`scrub()`]: Self::scrub
+pub struct Scrubber {
+    inner: Mutex<Inner>,
+}
+
+struct Inner {
+    entries: BTreeMap<Box<str>, Entry>,
+    searcher: Option<Searcher>,
+}
+
+struct Entry {
+    replacement: Box<str>,
+    count: usize,
+}
+
+#[derive(Clone)]
+struct Searcher {
+    automaton: Arc<AhoCorasick>,
+    replacements: Arc<[Box<str>]>,
+}
+
+impl Scrubber {
+    /// Constructs a new scrubber with no registered secrets
+    pub const fn new() -> Self {
+        Self {
+            inner: Mutex::new(Inner {
+                entries: BTreeMap::new(),
+                searcher: None,
+            }),
+        }
+    }
+
+    /// The process-wide scrubber, with which `secret(scrub)` braids are registered
+    pub fn global() -> &'static Self {
+        static GLOBAL: Scrubber = Scrubber::new();
+        &GLOBAL
+    }
+
+    /// Registers a secret, using its redaction message as the replacement
+    ///
+    /// The message is written by its [`StaticRedaction`] implementation, so it does not
+    /// depend on the redaction policy, never includes a fingerprint, and is not reported to
+    /// the audit hook of the braid.
+    pub fn register<T: ?Sized + AsRef<str> + StaticRedaction>(&self, secret: &T) {
+        let replacement = StaticallyRedacted(secret).to_string();
+        self.insert(secret.as_ref(), &replacement);
+    }
+
+    /// Registers a secret to be replaced with `replacement`
+    ///
+    /// If the secret is already registered, its registration count is incremented and the
+    /// existing replacement is kept. Empty secrets are ignored.
+    pub fn insert(&self, secret: &str, replacement: &str) {
+        if secret.is_empty() {
+            return;
+        }
+
+        let mut inner = self.lock();
+        if let Some(entry) = inner.entries.get_mut(secret) {
+            entry.count += 1;
+            return;
+        }
+
+        inner.entries.insert(
+            secret.into(),
+            Entry {
+                replacement: replacement.into(),
+                count: 1,
+            },
+        );
+        inner.searcher = None;
+    }
+
+    /// Removes one registration of a secret
+    ///
+    /// The secret is no longer scrubbed once every registration has been removed.
+    pub fn remove(&self, secret: &str) {
+        let mut inner = self.lock();
+        let entry = match inner.entries.get_mut(secret) {
+            Some(entry) => entry,
+            None => return,
+        };
+
+        entry.count -= 1;
+        if entry.count == 0 {
+            if let Some((key, _)) = inner.entries.remove_entry(secret) {
+                wipe_box(key);
+            }
+            inner.searcher = None;
+        }
+    }
+
+    /// Removes all registered secrets
+    pub fn clear(&self) {
+        let mut inner = self.lock();
+        for (key, _) in std::mem::take(&mut inner.entries) {
+            wipe_box(key);
+        }
+        inner.searcher = None;
+    }
+
+    /// The number of distinct registered secrets
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Whether no secrets are registered
+    pub fn is_empty(&self) -> bool {
+        self.lock().entries.is_empty()
+    }
+
+    /// Replaces every registered secret found in `text` with its replacement
+    ///
+    /// Where registered secrets overlap, the longest is replaced. The text is only copied
+    /// if a secret is found.
+    pub fn scrub<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let searcher = match self.searcher() {
+            Some(searcher) => searcher,
+            None => return Cow::Borrowed(text),
+        };
+
+        let mut scrubbed = String::new();
+        let mut last = 0;
+        for m in searcher.automaton.find_iter(text) {
+            scrubbed.push_str(&text[last..m.start()]);
+            scrubbed.push_str(&searcher.replacements[m.pattern().as_usize()]);
+            last = m.end();
+        }
+
+        // Secrets are never empty, so no match was found
+        if last == 0 {
+            return Cow::Borrowed(text);
+        }
+
+        scrubbed.push_str(&text[last..]);
+        Cow::Owned(scrubbed)
+    }
+
+    fn searcher(&self) -> Option<Searcher> {
+        let mut inner = self.lock();
+        if inner.entries.is_empty() {
+            return None;
+        }
+
+        if inner.searcher.is_none() {
+            let automaton = AhoCorasick::builder()
+                .match_kind(MatchKind::LeftmostLongest)
+                .build(inner.entries.keys().map(|k| k.as_bytes()))
+                .expect("registered secrets exceed the limits of the scrubber");
+            let replacements = inner
+                .entries
+                .values()
+                .map(|e| e.replacement.clone())
+                .collect::<Vec<_>>();
+
+            inner.searcher = Some(Searcher {
+                automaton: Arc::new(automaton),
+                replacements: replacements.into(),
+            });
+        }
+
+        inner.searcher.clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for Scrubber {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Scrubber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Scrubber")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        for (key, _) in std::mem::take(&mut self.entries) {
+            wipe_box(key);
+        }
+    }
+}
+
+fn wipe_box(key: Box<str>) {
+    let mut bytes = key.into_boxed_bytes();
+    crate::wipe::wipe(&mut bytes);
+}
@@ -0,0 +1,408 @@
+//! Sealed serialization of secrets
+//!
+//! Braids declared with `serde = "sealed"` are serialized as a string holding the value
+//! encrypted by the process-wide [`Sealer`], rather than as plain text. Deserialization
+//! unseals the value and then validates or normalizes it as `FromStr` would. This allows
+//! secrets, such as OAuth refresh tokens, to be persisted without being exposed.
+//!
+//! ```
+//! use aliri_braid::{braid, seal};
+//!
+//! #[braid(secret, serde = "sealed")]
+//! pub struct RefreshToken;
+//!
+//! seal::set_sealer(seal::TestSealer::new("2024-01", b"not a real key"));
+//!
+//! let token = RefreshToken::from_static("hunter2");
+//! let json = serde_json::to_string(&token).unwrap();
+//! assert!(json.starts_with("\"2024-01."));
+//! assert!(!json.contains("hunter2"));
+//!
+//! let unsealed: RefreshToken = serde_json::from_str(&json).unwrap();
+//! assert_eq!(token, unsealed);
+//! ```
+//!
+//! A sealed value is serialized as the ID of the key that sealed it, followed by a `.` and
+//! the unpadded, URL-safe base64 encoding of the ciphertext. Because the key ID is kept
+//! with the value, keys can be rotated by installing a sealer that seals with a new key but
+//! can still unseal values sealed with older ones.
+//!
+//! Any buffers used to hold the unsealed value are wiped before being freed, and errors
+//! never include the value.
+
+use std::{
+    boxed::Box,
+    error, fmt,
+    string::{String, ToString},
+    sync::{Arc, RwLock},
+    vec::Vec,
+};
+
+use crate::wipe::Scratch;
+
+/// Encrypts and decrypts the values of braids declared with `serde = "sealed"`
+///
+/// The name of the braid type is provided with each value, and should be bound to the
+/// ciphertext, such as by using it as associated data, so that a value sealed for one
+/// type cannot be unsealed as another.
+///
+/// Implementations should use an authenticated encryption scheme, such as AES-GCM or
+/// XChaCha20-Poly1305, with keys provided at runtime.
+pub trait Sealer: Send + Sync + 'static {
+    /// Seals a value with the current key
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value cannot be sealed.
+    fn seal(&self, type_name: &str, plaintext: &[u8]) -> Result<Sealed, SealErrorKind>;
+
+    /// Unseals a value with the key identified by [`Sealed::key_id()`]
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SealErrorKind::UnknownKey`] if the sealer does not have the key, or
+    /// another error if the value cannot be unsealed.
+    fn unseal(&self, type_name: &str, sealed: &Sealed) -> Result<Vec<u8>, SealErrorKind>;
+}
+
+static SEALER: RwLock<Option<Arc<dyn Sealer>>> = RwLock::new(None);
+
+/// Installs the process-wide sealer, replacing any that was installed previously
+///
+/// Replacing the sealer is how keys are rotated: the new sealer should seal with the new
+/// key, while still being able to unseal values sealed with older keys.
+pub fn set_sealer(sealer: impl Sealer) {
+    let mut current = SEALER.write().unwrap_or_else(|e| e.into_inner());
+    *current = Some(Arc::new(sealer));
+}
+
+fn sealer() -> Option<Arc<dyn Sealer>> {
+    SEALER.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Seals a value with the process-wide sealer, returning its serialized form
+///
+/// # Errors
+///
+/// Returns an error if no sealer has been installed, or if the sealer fails.
+pub fn seal(type_name: &'static str, plaintext: &str) -> Result<String, SealError> {
+    let error = |kind| SealError::new(type_name, Operation::Seal, kind);
+    let sealer = sealer().ok_or_else(|| error(SealErrorKind::NoSealer))?;
+    let sealed = sealer
+        .seal(type_name, plaintext.as_bytes())
+        .map_err(error)?;
+    Ok(sealed.to_string())
+}
+
+/// Unseals a value from its serialized form with the process-wide sealer
+///
+/// The unsealed value is passed to `parse`, which should validate it.
+///
+/// # Errors
+///
+/// Returns an error if no sealer has been installed, if the value is malformed or cannot be
+/// unsealed, or if it is rejected by `parse`.
+pub fn unseal<T, E>(
+    type_name: &'static str,
+    sealed: &str,
+    parse: impl FnOnce(&str) -> Result<T, E>,
+) -> Result<T, SealError> {
+    let error = |kind| SealError::new(type_name, Operation::Unseal, kind);
+    let sealer = sealer().ok_or_else(|| error(SealErrorKind::NoSealer))?;
+    let sealed = sealed.parse::<Sealed>().map_err(error)?;
+    let plaintext = Scratch(sealer.unseal(type_name, &sealed).map_err(error)?);
+    let plaintext =
+        core::str::from_utf8(&plaintext.0).map_err(|_| error(SealErrorKind::Malformed))?;
+    parse(plaintext).map_err(|_| error(SealErrorKind::Invalid))
+}
+
+/// A sealed value, along with the ID of the key that sealed it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sealed {
+    key_id: String,
+    ciphertext: Vec<u8>,
+}
+
+impl Sealed {
+    /// Constructs a sealed value
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key_id` is empty.
+    pub fn new(key_id: impl Into<String>, ciphertext: Vec<u8>) -> Self {
+        let key_id = key_id.into();
+        assert!(!key_id.is_empty(), "key ID must not be empty");
+        Self { key_id, ciphertext }
+    }
+
+    /// The ID of the key that sealed the value
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    /// The sealed value
+    pub fn ciphertext(&self) -> &[u8] {
+        &self.ciphertext
+    }
+}
+
+impl fmt::Display for Sealed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.key_id)?;
+        f.write_str(".")?;
+        base64url::encode(f, &self.ciphertext)
+    }
+}
+
+impl core::str::FromStr for Sealed {
+    type Err = SealErrorKind;
+
+    /// Parses the serialized form of a sealed value
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The base64 alphabet does not include `.`, so the key ID may contain it
+        let (key_id, ciphertext) = s.rsplit_once('.').ok_or(SealErrorKind::Malformed)?;
+        if key_id.is_empty() {
+            return Err(SealErrorKind::Malformed);
+        }
+
+        let ciphertext = base64url::decode(ciphertext).ok_or(SealErrorKind::Malformed)?;
+        Ok(Self::new(key_id, ciphertext))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Operation {
+    Seal,
+    Unseal,
+}
+
+/// The reason that a value could not be sealed or unsealed
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SealErrorKind {
+    /// No sealer has been installed with [`set_sealer()`]
+    NoSealer,
+
+    /// The value was sealed with a key that the sealer does not have
+    UnknownKey(String),
+
+    /// The sealed value is not in the expected format
+    Malformed,
+
+    /// The unsealed value was rejected by the braid's validator or normalizer
+    Invalid,
+
+    /// The sealer failed to seal or unseal the value
+    Failed(Box<dyn error::Error + Send + Sync>),
+}
+
+impl fmt::Display for SealErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NoSealer => f.write_str("no sealer installed"),
+            Self::UnknownKey(key_id) => write!(f, "unknown key `{key_id}`"),
+            Self::Malformed => f.write_str("malformed sealed value"),
+            Self::Invalid => f.write_str("invalid value"),
+            Self::Failed(e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+/// An error encountered while sealing or unsealing a value
+///
+/// Neither the `Debug` nor the `Display` output of this error include the value.
+#[derive(Debug)]
+pub struct SealError {
+    type_name: &'static str,
+    operation: Operation,
+    kind: SealErrorKind,
+}
+
+impl SealError {
+    fn new(type_name: &'static str, operation: Operation, kind: SealErrorKind) -> Self {
+        Self {
+            type_name,
+            operation,
+            kind,
+        }
+    }
+
+    /// The name of the type being sealed or unsealed
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The reason that the value could not be sealed or unsealed
+    pub fn kind(&self) -> &SealErrorKind {
+        &self.kind
+    }
+
+    /// Unwraps the reason that the value could not be sealed or unsealed
+    pub fn into_kind(self) -> SealErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for SealError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let operation = match self.operation {
+            Operation::Seal => "seal",
+            Operation::Unseal => "unseal",
+        };
+        write!(
+            f,
+            "failed to {} `{}`: {}",
+            operation, self.type_name, self.kind
+        )
+    }
+}
+
+impl error::Error for SealError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match &self.kind {
+            SealErrorKind::Failed(e) => Some(&**e),
+            _ => None,
+        }
+    }
+}
+
+/// A sealer for tests, which provides **no confidentiality**
+///
+/// Values are obscured by XORing them with the key, and bound to the type name with a
+/// checksum, so that sealing, unsealing, and key rotation can be exercised without
+/// depending on a cryptography library. It must never be used to protect real secrets.
+///
+/// ```
+/// use aliri_braid::seal::{Sealer, TestSealer};
+///
+/// let old = TestSealer::new("k1", b"old key");
+/// let sealed = old.seal("ApiKey", b"hunter2").unwrap();
+///
+/// let rotated = old.with_key("k2", b"new key");
+/// assert_eq!("k2", rotated.seal("ApiKey", b"hunter2").unwrap().key_id());
+/// assert_eq!(b"hunter2".to_vec(), rotated.unseal("ApiKey", &sealed).unwrap());
+/// assert!(rotated.unseal("OtherKey", &sealed).is_err());
+/// ```
+pub struct TestSealer {
+    keys: Vec<(String, Vec<u8>)>,
+}
+
+impl TestSealer {
+    /// Constructs a sealer that seals with the given key
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key_id` or `key` is empty.
+    pub fn new(key_id: &str, key: &[u8]) -> Self {
+        Self { keys: Vec::new() }.with_key(key_id, key)
+    }
+
+    /// Adds a key, which is used to seal new values
+    ///
+    /// Values sealed with previously added keys can still be unsealed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key_id` or `key` is empty.
+    #[must_use]
+    pub fn with_key(mut self, key_id: &str, key: &[u8]) -> Self {
+        assert!(!key_id.is_empty(), "key ID must not be empty");
+        assert!(!key.is_empty(), "key must not be empty");
+        self.keys.push((key_id.into(), key.into()));
+        self
+    }
+
+    fn xor(key: &[u8], data: &mut [u8]) {
+        for (b, k) in data.iter_mut().zip(key.iter().cycle()) {
+            *b ^= k;
+        }
+    }
+
+    fn checksum(type_name: &str, plaintext: &[u8]) -> [u8; 4] {
+        let mut hash = 0x811c_9dc5_u32;
+        for &b in type_name.as_bytes().iter().chain(b"\0").chain(plaintext) {
+            hash = (hash ^ u32::from(b)).wrapping_mul(0x0100_0193);
+        }
+        hash.to_be_bytes()
+    }
+}
+
+impl Sealer for TestSealer {
+    fn seal(&self, type_name: &str, plaintext: &[u8]) -> Result<Sealed, SealErrorKind> {
+        let (key_id, key) = self.keys.last().expect("at least one key");
+        let mut ciphertext = Vec::with_capacity(plaintext.len() + 4);
+        ciphertext.extend_from_slice(plaintext);
+        ciphertext.extend_from_slice(&Self::checksum(type_name, plaintext));
+        Self::xor(key, &mut ciphertext);
+        Ok(Sealed::new(key_id.as_str(), ciphertext))
+    }
+
+    fn unseal(&self, type_name: &str, sealed: &Sealed) -> Result<Vec<u8>, SealErrorKind> {
+        let (_, key) = self
+            .keys
+            .iter()
+            .find(|(id, _)| id == sealed.key_id())
+            .ok_or_else(|| SealErrorKind::UnknownKey(sealed.key_id().into()))?;
+
+        let len = sealed
+            .ciphertext()
+            .len()
+            .checked_sub(4)
+            .ok_or(SealErrorKind::Malformed)?;
+        let mut plaintext = sealed.ciphertext().to_vec();
+        Self::xor(key, &mut plaintext);
+        let checksum = plaintext.split_off(len);
+        if checksum != Self::checksum(type_name, &plaintext) {
+            crate::wipe::wipe(&mut plaintext);
+            return Err(SealErrorKind::Failed("checksum mismatch".into()));
+        }
+
+        Ok(plaintext)
+    }
+}
+
+impl fmt::Debug for TestSealer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TestSealer").finish_non_exhaustive()
+    }
+}
+
+mod base64url {
+    use core::fmt;
+    use std::vec::Vec;
+
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+    pub(super) fn encode(f: &mut fmt::Formatter, data: &[u8]) -> fmt::Result {
+        use fmt::Write;
+
+        for chunk in data.chunks(3) {
+            let n = chunk
+                .iter()
+                .enumerate()
+                .fold(0u32, |n, (i, &b)| n | u32::from(b) << (16 - 8 * i));
+            for i in 0..=chunk.len() {
+                let index = (n >> (18 - 6 * i)) & 0x3f;
+                f.write_char(char::from(ALPHABET[index as usize]))?;
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn decode(s: &str) -> Option<Vec<u8>> {
+        if s.len() % 4 == 1 {
+            return None;
+        }
+
+        let mut data = Vec::with_capacity(s.len() * 3 / 4);
+        for chunk in s.as_bytes().chunks(4) {
+            let mut n = 0u32;
+            for (i, &c) in chunk.iter().enumerate() {
+                let value = ALPHABET.iter().position(|&a| a == c)?;
+                n |= (value as u32) << (18 - 6 * i);
+            }
+            data.extend_from_slice(&n.to_be_bytes()[1..chunk.len()]);
+        }
+        Some(data)
+    }
+}
@@ -0,0 +1,171 @@
+use core::{fmt, ops::Range};
+
+/// An error describing which rule a value broke, and where
+///
+/// A `ValidationError` identifies the broken rule with a short identifier, such as
+/// `"max_len"` or `"charset"`, along with the byte offset and length of the offending part of
+/// the value. It never holds the value itself, but can [render][Self::render()] the value
+/// with the offending part underlined.
+///
+/// The [built-in validators][crate::validators] produce this error, and the error enums
+/// generated by `validate(...)` convert into it. When a braid rejects a value, the name of the
+/// braid is added to the error, and the error is marked as redacted if the braid is a secret.
+///
+/// ```
+/// use aliri_braid::{braid, validators::AsciiPrintable};
+///
+/// #[braid(validator = "AsciiPrintable")]
+/// pub struct Subject;
+///
+/// let err = Subject::new("Hello,\tworld!".to_owned()).unwrap_err();
+/// assert_eq!(Some("Subject"), err.type_name());
+/// assert_eq!("ascii_printable", err.rule());
+/// assert_eq!(6..7, err.span());
+/// assert_eq!(
+///     "invalid `Subject`: value must only contain printable ASCII characters\n\
+///      \x20 | Hello,\\tworld!\n\
+///      \x20 |       ^^",
+///     err.render("Hello,\tworld!").to_string(),
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    type_name: Option<&'static str>,
+    rule: &'static str,
+    message: &'static str,
+    offset: usize,
+    len: usize,
+    redacted: bool,
+}
+
+impl ValidationError {
+    /// Creates an error for the broken `rule`, and the offending span of the value
+    ///
+    /// The `message` describes the rule, and must not include the value.
+    pub const fn new(rule: &'static str, message: &'static str, offset: usize, len: usize) -> Self {
+        Self {
+            type_name: None,
+            rule,
+            message,
+            offset,
+            len,
+            redacted: false,
+        }
+    }
+
+    /// Adds the name of the braid that rejected the value, and whether it is a secret
+    ///
+    /// Validators producing this error call this from
+    /// [`Validator::annotate_error()`][crate::Validator::annotate_error()].
+    #[must_use]
+    pub const fn for_braid(mut self, type_name: &'static str, secret: bool) -> Self {
+        self.type_name = Some(type_name);
+        self.redacted = secret;
+        self
+    }
+
+    /// The name of the braid that rejected the value, if known
+    pub const fn type_name(&self) -> Option<&'static str> {
+        self.type_name
+    }
+
+    /// The identifier of the broken rule
+    pub const fn rule(&self) -> &'static str {
+        self.rule
+    }
+
+    /// A description of the broken rule
+    pub const fn message(&self) -> &'static str {
+        self.message
+    }
+
+    /// The byte offset of the offending part of the value
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The byte range of the offending part of the value
+    ///
+    /// The range is empty when the problem is at a position rather than with particular
+    /// characters, such as when a value is too short.
+    pub const fn span(&self) -> Range<usize> {
+        self.offset..self.offset + self.len
+    }
+
+    /// Whether the value was rejected by a secret braid, and so must not be rendered
+    pub const fn is_redacted(&self) -> bool {
+        self.redacted
+    }
+
+    /// Renders the rejected value with the offending part underlined
+    ///
+    /// Characters in the value are escaped as with [`char::escape_debug()`], so the rendering
+    /// always fits on one line. If the error is [redacted][Self::is_redacted()], the value is
+    /// replaced by `[redacted]`.
+    pub fn render<'a>(&'a self, input: &'a str) -> RenderedError<'a> {
+        RenderedError { error: self, input }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(type_name) = self.type_name {
+            write!(f, "invalid `{type_name}`: ")?;
+        }
+        f.write_str(self.message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
+
+crate::from_infallible!(ValidationError);
+
+/// A [`ValidationError`] rendered along with the rejected value
+///
+/// Created by [`ValidationError::render()`].
+pub struct RenderedError<'a> {
+    error: &'a ValidationError,
+    input: &'a str,
+}
+
+impl fmt::Debug for RenderedError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RenderedError")
+            .field("error", self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for RenderedError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.error)?;
+        if self.error.redacted {
+            return f.write_str("  | [redacted]");
+        }
+
+        f.write_str("  | ")?;
+        let span = self.error.span();
+        let (mut before, mut under) = (0, 0);
+        for (offset, ch) in self.input.char_indices() {
+            let width = ch.escape_debug().count();
+            if offset < span.start {
+                before += width;
+            } else if offset < span.end {
+                under += width;
+            }
+            for escaped in ch.escape_debug() {
+                fmt::Write::write_char(f, escaped)?;
+            }
+        }
+
+        f.write_str("\n  | ")?;
+        for _ in 0..before {
+            f.write_str(" ")?;
+        }
+        for _ in 0..under.max(1) {
+            f.write_str("^")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,384 @@
+use core::{convert::Infallible, fmt, marker::PhantomData};
+
+use crate::Validator;
+
+/// Accepts a value only if it is accepted by every validator in the tuple `T`
+///
+/// Validators are checked in order, and the first rejection is returned as an [`AllError`],
+/// whose variant identifies the validator that rejected the value. [`Validator::validate_all()`]
+/// instead checks every validator, and reports each of their errors. Tuples of up to eight
+/// validators are supported.
+///
+/// ```
+/// use aliri_braid::{
+///     braid,
+///     validators::{All, AllError, Any, Not},
+///     Validator,
+/// };
+/// # #[derive(Debug, PartialEq)]
+/// # pub struct Rejected(&'static str);
+/// # impl std::fmt::Display for Rejected {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #         f.write_str(self.0)
+/// #     }
+/// # }
+/// # aliri_braid::from_infallible!(Rejected);
+///
+/// pub struct Lowercase;
+///
+/// impl Validator for Lowercase {
+///     type Error = Rejected;
+///
+///     fn validate(raw: &str) -> Result<(), Self::Error> {
+///         if raw.bytes().all(|b| b.is_ascii_lowercase()) {
+///             Ok(())
+///         } else {
+///             Err(Rejected("value must be lowercase"))
+///         }
+///     }
+/// }
+///
+/// pub struct Short;
+///
+/// impl Validator for Short {
+///     type Error = Rejected;
+///
+///     fn validate(raw: &str) -> Result<(), Self::Error> {
+///         if raw.len() <= 8 {
+///             Ok(())
+///         } else {
+///             Err(Rejected("value must be at most 8 bytes long"))
+///         }
+///     }
+/// }
+///
+/// #[braid(validator = "All<(Lowercase, Short)>")]
+/// pub struct Nickname;
+///
+/// assert!(Nickname::new("bob".to_owned()).is_ok());
+/// assert_eq!(
+///     AllError::Second(Rejected("value must be at most 8 bytes long")),
+///     Nickname::new("bartholomew".to_owned()).unwrap_err(),
+/// );
+///
+/// #[braid(validator = "Any<(Lowercase, Short)>")]
+/// pub struct Relaxed;
+///
+/// assert!(Relaxed::new("bartholomew".to_owned()).is_ok());
+/// assert!(Relaxed::new("BOB".to_owned()).is_ok());
+/// assert!(Relaxed::new("BARTHOLOMEW".to_owned()).is_err());
+///
+/// #[braid(validator = "All<(Short, Not<Lowercase>)>")]
+/// pub struct NotLowercase;
+///
+/// assert!(NotLowercase::new("Bob".to_owned()).is_ok());
+/// assert!(matches!(
+///     NotLowercase::new("bob".to_owned()),
+///     Err(AllError::Second(_)),
+/// ));
+/// ```
+pub struct All<T>(PhantomData<fn() -> T>);
+
+/// Accepts a value if it is accepted by any validator in the tuple `T`
+///
+/// Validators are checked in order until one accepts the value. If every validator rejects
+/// the value, all of their errors are returned as an [`AnyError`]. As the alternatives only fail
+/// together, [`Validator::validate_all()`] reports a single `AnyError`. Tuples of up to eight
+/// validators are supported.
+pub struct Any<T>(PhantomData<fn() -> T>);
+
+/// Accepts a value only if it is rejected by the validator `V`
+pub struct Not<V>(PhantomData<fn() -> V>);
+
+/// Validates with `V`, converting its error into `E`
+///
+/// This allows a combination of validators to report the error type of the braid's own
+/// choosing. As `E` may be any type, the details of the rejecting braid are not added to the
+/// error by [`Validator::annotate_error()`].
+pub struct Map<V, E>(PhantomData<fn() -> (V, E)>);
+
+impl<T> fmt::Debug for All<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("All")
+    }
+}
+
+impl<T> fmt::Debug for Any<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Any")
+    }
+}
+
+impl<V> fmt::Debug for Not<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Not")
+    }
+}
+
+impl<V, E> fmt::Debug for Map<V, E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Map")
+    }
+}
+
+/// The error produced when one of the validators of an [`All`] rejects a value
+///
+/// The variant identifies the position of the validator that rejected the value. Positions
+/// beyond the length of the tuple use [`Infallible`], and so can never occur.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AllError<
+    E0,
+    E1 = Infallible,
+    E2 = Infallible,
+    E3 = Infallible,
+    E4 = Infallible,
+    E5 = Infallible,
+    E6 = Infallible,
+    E7 = Infallible,
+> {
+    /// The first validator rejected the value
+    First(E0),
+    /// The second validator rejected the value
+    Second(E1),
+    /// The third validator rejected the value
+    Third(E2),
+    /// The fourth validator rejected the value
+    Fourth(E3),
+    /// The fifth validator rejected the value
+    Fifth(E4),
+    /// The sixth validator rejected the value
+    Sixth(E5),
+    /// The seventh validator rejected the value
+    Seventh(E6),
+    /// The eighth validator rejected the value
+    Eighth(E7),
+}
+
+impl<E0, E1, E2, E3, E4, E5, E6, E7> AllError<E0, E1, E2, E3, E4, E5, E6, E7> {
+    /// The zero-based position of the validator that rejected the value
+    pub const fn index(&self) -> usize {
+        match self {
+            Self::First(_) => 0,
+            Self::Second(_) => 1,
+            Self::Third(_) => 2,
+            Self::Fourth(_) => 3,
+            Self::Fifth(_) => 4,
+            Self::Sixth(_) => 5,
+            Self::Seventh(_) => 6,
+            Self::Eighth(_) => 7,
+        }
+    }
+}
+
+macro_rules! all_error_delegate {
+    ($self:ident, $e:ident => $body:expr) => {
+        match $self {
+            Self::First($e) => $body,
+            Self::Second($e) => $body,
+            Self::Third($e) => $body,
+            Self::Fourth($e) => $body,
+            Self::Fifth($e) => $body,
+            Self::Sixth($e) => $body,
+            Self::Seventh($e) => $body,
+            Self::Eighth($e) => $body,
+        }
+    };
+}
+
+impl<E0, E1, E2, E3, E4, E5, E6, E7> fmt::Display for AllError<E0, E1, E2, E3, E4, E5, E6, E7>
+where
+    E0: fmt::Display,
+    E1: fmt::Display,
+    E2: fmt::Display,
+    E3: fmt::Display,
+    E4: fmt::Display,
+    E5: fmt::Display,
+    E6: fmt::Display,
+    E7: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        all_error_delegate!(self, e => fmt::Display::fmt(e, f))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E0, E1, E2, E3, E4, E5, E6, E7> std::error::Error for AllError<E0, E1, E2, E3, E4, E5, E6, E7>
+where
+    E0: std::error::Error,
+    E1: std::error::Error,
+    E2: std::error::Error,
+    E3: std::error::Error,
+    E4: std::error::Error,
+    E5: std::error::Error,
+    E6: std::error::Error,
+    E7: std::error::Error,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        all_error_delegate!(self, e => e.source())
+    }
+}
+
+impl<E0, E1, E2, E3, E4, E5, E6, E7> From<Infallible> for AllError<E0, E1, E2, E3, E4, E5, E6, E7> {
+    #[inline(always)]
+    fn from(x: Infallible) -> Self {
+        match x {}
+    }
+}
+
+/// The error produced when every validator of an [`Any`] rejects a value
+///
+/// Holds a tuple of the errors from each validator, in order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnyError<T>(pub T);
+
+impl<T> From<Infallible> for AnyError<T> {
+    #[inline(always)]
+    fn from(x: Infallible) -> Self {
+        match x {}
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for AnyError<T> where Self: fmt::Debug + fmt::Display {}
+
+/// The error produced when the validator of a [`Not`] accepts a value
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NotError {
+    validator: &'static str,
+}
+
+impl NotError {
+    /// The name of the validator that accepted the value
+    pub const fn validator(&self) -> &'static str {
+        self.validator
+    }
+}
+
+impl fmt::Display for NotError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "value must not be accepted by `{}`", self.validator)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NotError {}
+
+crate::from_infallible!(NotError);
+
+impl<V: Validator> Validator for Not<V> {
+    type Error = NotError;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        match V::validate(raw) {
+            Ok(()) => Err(NotError {
+                validator: core::any::type_name::<V>(),
+            }),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+impl<V, E> Validator for Map<V, E>
+where
+    V: Validator,
+    E: From<V::Error>,
+{
+    type Error = E;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        V::validate(raw).map_err(E::from)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn validate_all(raw: &str) -> Result<(), alloc::vec::Vec<Self::Error>> {
+        V::validate_all(raw).map_err(|errors| errors.into_iter().map(E::from).collect())
+    }
+}
+
+macro_rules! impl_tuples {
+    ($( ($($v:ident $e:ident $variant:ident),+) )+) => {$(
+        impl<$($v: Validator),+> Validator for All<($($v,)+)> {
+            type Error = AllError<$($v::Error),+>;
+
+            fn validate(raw: &str) -> Result<(), Self::Error> {
+                $($v::validate(raw).map_err(AllError::$variant)?;)+
+                Ok(())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn validate_all(raw: &str) -> Result<(), alloc::vec::Vec<Self::Error>> {
+                let mut errors = alloc::vec::Vec::new();
+                $(
+                    if let Err(e) = $v::validate_all(raw) {
+                        errors.extend(e.into_iter().map(AllError::$variant));
+                    }
+                )+
+                if errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(errors)
+                }
+            }
+
+            #[allow(unreachable_patterns)]
+            fn annotate_error(
+                error: Self::Error,
+                type_name: &'static str,
+                secret: bool,
+            ) -> Self::Error {
+                match error {
+                    $(AllError::$variant(e) => {
+                        AllError::$variant($v::annotate_error(e, type_name, secret))
+                    })+
+                    other => other,
+                }
+            }
+        }
+
+        impl<$($v: Validator),+> Validator for Any<($($v,)+)> {
+            type Error = AnyError<($($v::Error,)+)>;
+
+            fn validate(raw: &str) -> Result<(), Self::Error> {
+                $(
+                    #[allow(non_snake_case)]
+                    let $e = match $v::validate(raw) {
+                        Ok(()) => return Ok(()),
+                        Err(e) => e,
+                    };
+                )+
+                Err(AnyError(($($e,)+)))
+            }
+
+            #[allow(non_snake_case)]
+            fn annotate_error(
+                error: Self::Error,
+                type_name: &'static str,
+                secret: bool,
+            ) -> Self::Error {
+                let ($($e,)+) = error.0;
+                AnyError(($($v::annotate_error($e, type_name, secret),)+))
+            }
+        }
+
+        impl<$($e: fmt::Display),+> fmt::Display for AnyError<($($e,)+)> {
+            #[allow(non_snake_case)]
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let ($($e,)+) = &self.0;
+                f.write_str("value was rejected by every alternative")?;
+                $(write!(f, "; {}", $e)?;)+
+                Ok(())
+            }
+        }
+    )+};
+}
+
+impl_tuples! {
+    (A EA First)
+    (A EA First, B EB Second)
+    (A EA First, B EB Second, C EC Third)
+    (A EA First, B EB Second, C EC Third, D ED Fourth)
+    (A EA First, B EB Second, C EC Third, D ED Fourth, E EE Fifth)
+    (A EA First, B EB Second, C EC Third, D ED Fourth, E EE Fifth, F EF Sixth)
+    (A EA First, B EB Second, C EC Third, D ED Fourth, E EE Fifth, F EF Sixth, G EG Seventh)
+    (A EA First, B EB Second, C EC Third, D ED Fourth, E EE Fifth, F EF Sixth, G EG Seventh, H EH Eighth)
+}
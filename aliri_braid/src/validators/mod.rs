@@ -0,0 +1,56 @@
+//! Reusable validators
+//!
+//! These types implement [`Validator`][crate::Validator], and can be used directly as the
+//! validator of a braid with `validator = "aliri_braid::validators::..."`. Except for
+//! [`Strength`], each reports a rejection as a [`ValidationError`][crate::ValidationError],
+//! identifying the broken rule and the offending part of the value.
+//!
+//! | Validator              | Rejects values                                          |
+//! |------------------------|---------------------------------------------------------|
+//! | [`NonEmpty`]           | that are empty                                          |
+//! | [`MinLen<N>`][MinLen]  | shorter than `N` bytes                                  |
+//! | [`MaxLen<N>`][MaxLen]  | longer than `N` bytes                                   |
+//! | [`Ascii`]              | containing non-ASCII characters                         |
+//! | [`AsciiPrintable`]     | containing characters other than printable ASCII        |
+//! | [`NoControlChars`]     | containing control characters                           |
+//! | [`NoNul`]              | containing a NUL character                              |
+//! | [`Trimmed`]            | with leading or trailing whitespace                     |
+//! | [`Strength`]           | that are easy to guess, such as weak passwords          |
+//!
+//! [`Lowercase`] and [`TrimWhitespace`] also implement [`Normalizer`][crate::Normalizer], and
+//! can be used with `normalizer = "aliri_braid::validators::..."` to convert values into
+//! lowercase or remove surrounding whitespace. All validators are available without `std` or
+//! `alloc`, while the normalizers require the `alloc` feature, like the
+//! [`Normalizer`][crate::Normalizer] trait itself.
+//!
+//! Validators can be combined without a hand-written implementation using [`All`], [`Any`],
+//! [`Not`], and [`Map`].
+//!
+//! ```
+//! use aliri_braid::{
+//!     braid,
+//!     validators::{All, AllError, AsciiPrintable, MaxLen, NonEmpty},
+//! };
+//!
+//! #[braid(validator = "All<(NonEmpty, AsciiPrintable, MaxLen<255>)>")]
+//! pub struct Subject;
+//!
+//! assert!(Subject::new("Hello, world!".to_owned()).is_ok());
+//!
+//! let err = Subject::new("Hello\nworld".to_owned()).unwrap_err();
+//! let AllError::Second(err) = err else { unreachable!() };
+//! assert_eq!("ascii_printable", err.rule());
+//! assert_eq!(5..6, err.span());
+//! ```
+
+mod combinators;
+mod normalizers;
+mod strength;
+mod text;
+
+pub use combinators::{All, AllError, Any, AnyError, Map, Not, NotError};
+pub use normalizers::{Lowercase, TrimWhitespace};
+pub use strength::{
+    estimate_bits, CharClasses, Recommended, Strength, StrengthError, StrengthPolicy,
+};
+pub use text::{Ascii, AsciiPrintable, MaxLen, MinLen, NoControlChars, NoNul, NonEmpty, Trimmed};
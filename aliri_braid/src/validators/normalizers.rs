@@ -0,0 +1,83 @@
+use super::text::{annotate_error, find_char, Trimmed};
+use crate::{ValidationError, Validator};
+
+/// Normalizes values to lowercase
+///
+/// As a validator, rejects values containing any character that changes when converted to
+/// lowercase. As a [`Normalizer`][crate::Normalizer], converts such values with
+/// [`str::to_lowercase()`]. Case conversion follows the Unicode rules, not only the ASCII
+/// rules.
+///
+/// ```
+/// use aliri_braid::{braid, validators::Lowercase};
+///
+/// #[braid(normalizer = "Lowercase")]
+/// pub struct Email;
+///
+/// assert_eq!("alice@example.com", Email::from_static("Alice@Example.com").as_str());
+/// assert!(EmailRef::from_normalized_str("Alice@Example.com").is_err());
+/// ```
+#[derive(Debug)]
+pub struct Lowercase;
+
+fn is_lowercase(ch: char) -> bool {
+    let mut lower = ch.to_lowercase();
+    lower.next() == Some(ch) && lower.next().is_none()
+}
+
+impl Validator for Lowercase {
+    type Error = ValidationError;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        find_char(raw, is_lowercase, "lowercase", "value must be lowercase")
+    }
+
+    annotate_error!();
+}
+
+#[cfg(feature = "alloc")]
+impl crate::Normalizer for Lowercase {
+    fn normalize(raw: &str) -> Result<::alloc::borrow::Cow<'_, str>, Self::Error> {
+        use alloc::borrow::Cow;
+
+        if raw.chars().all(is_lowercase) {
+            Ok(Cow::Borrowed(raw))
+        } else {
+            Ok(Cow::Owned(raw.to_lowercase()))
+        }
+    }
+}
+
+/// Normalizes values by removing leading and trailing whitespace
+///
+/// As a validator, behaves like [`Trimmed`]. As a [`Normalizer`][crate::Normalizer], removes
+/// the whitespace with [`str::trim()`], which never requires allocating.
+///
+/// ```
+/// use aliri_braid::{braid, validators::TrimWhitespace};
+///
+/// #[braid(normalizer = "TrimWhitespace")]
+/// pub struct DisplayName;
+///
+/// assert_eq!("Alice", DisplayName::new("  Alice\n".to_owned()).unwrap().as_str());
+/// assert!(DisplayNameRef::from_normalized_str(" Alice").is_err());
+/// ```
+#[derive(Debug)]
+pub struct TrimWhitespace;
+
+impl Validator for TrimWhitespace {
+    type Error = ValidationError;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        Trimmed::validate(raw)
+    }
+
+    annotate_error!();
+}
+
+#[cfg(feature = "alloc")]
+impl crate::Normalizer for TrimWhitespace {
+    fn normalize(raw: &str) -> Result<::alloc::borrow::Cow<'_, str>, Self::Error> {
+        Ok(::alloc::borrow::Cow::Borrowed(raw.trim()))
+    }
+}
@@ -0,0 +1,345 @@
+use core::{fmt, marker::PhantomData, ops};
+
+use crate::Validator;
+
+/// Rejects values that are easy to guess, such as weak passwords
+///
+/// The rules are provided by a [`StrengthPolicy`], which defaults to [`Recommended`]. A value
+/// is rejected if it contains a banned word, lacks a required class of characters, or has an
+/// [estimated entropy][estimate_bits] below the minimum.
+///
+/// ```
+/// use aliri_braid::{
+///     braid,
+///     validators::{CharClasses, Strength, StrengthError, StrengthPolicy},
+/// };
+///
+/// pub struct SignupPolicy;
+///
+/// impl StrengthPolicy for SignupPolicy {
+///     const MIN_BITS: u32 = 50;
+///     const BANNED_WORDS: &'static [&'static str] = &["password", "acme"];
+///     const REQUIRED_CLASSES: CharClasses = CharClasses::DIGIT;
+/// }
+///
+/// #[braid(secret, validator = "Strength<SignupPolicy>")]
+/// pub struct Password;
+///
+/// assert!(Password::new("correct horse battery 9 staple".to_owned()).is_ok());
+///
+/// let err = Password::new("MyAcmePassw0rd!".to_owned()).unwrap_err();
+/// assert_eq!(&StrengthError::BannedWord, err.get_ref());
+///
+/// let err = Password::new("ab1".to_owned()).unwrap_err();
+/// assert_eq!(
+///     "value is too weak: estimated 11 bits of entropy, but at least 50 are required",
+///     err.get_ref().to_string(),
+/// );
+/// ```
+///
+/// Errors describe which rule was broken, but never include the value.
+pub struct Strength<P = Recommended>(PhantomData<fn() -> P>);
+
+impl<P> fmt::Debug for Strength<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Strength")
+    }
+}
+
+/// The rules applied by [`Strength`]
+pub trait StrengthPolicy {
+    /// The minimum estimated entropy of a value, in bits
+    const MIN_BITS: u32;
+
+    /// Words that may not appear anywhere in a value, ignoring ASCII case
+    const BANNED_WORDS: &'static [&'static str] = &[];
+
+    /// Classes of characters that must each appear in a value
+    const REQUIRED_CLASSES: CharClasses = CharClasses::NONE;
+
+    /// The minimum number of distinct classes of characters that must appear in a value
+    const MIN_CLASSES: u32 = 0;
+}
+
+/// A policy requiring 60 bits of estimated entropy and rejecting some very common passwords
+#[derive(Debug)]
+pub struct Recommended;
+
+impl StrengthPolicy for Recommended {
+    const MIN_BITS: u32 = 60;
+    const BANNED_WORDS: &'static [&'static str] = &[
+        "password", "passw0rd", "qwerty", "letmein", "welcome", "iloveyou", "admin", "monkey",
+        "dragon", "123456",
+    ];
+}
+
+impl<P: StrengthPolicy> Validator for Strength<P> {
+    type Error = StrengthError;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        let banned = P::BANNED_WORDS
+            .iter()
+            .any(|word| contains_ignore_ascii_case(raw, word));
+        if banned {
+            return Err(StrengthError::BannedWord);
+        }
+
+        let classes = CharClasses::of(raw);
+        let missing = P::REQUIRED_CLASSES.difference(classes);
+        if !missing.is_empty() {
+            return Err(StrengthError::MissingClasses(missing));
+        }
+
+        if classes.len() < P::MIN_CLASSES {
+            return Err(StrengthError::TooFewClasses {
+                found: classes.len(),
+                required: P::MIN_CLASSES,
+            });
+        }
+
+        let estimated_bits = estimate_bits(raw);
+        if estimated_bits < P::MIN_BITS {
+            return Err(StrengthError::TooWeak {
+                estimated_bits,
+                min_bits: P::MIN_BITS,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Estimates the entropy of a value, in bits
+///
+/// The estimate assumes each character was drawn at random from the classes of characters
+/// present in the value. Characters that repeat the previous character, or that continue a
+/// run such as `abc` or `321`, contribute only a single bit. This is a coarse estimate that
+/// overstates the strength of values built from dictionary words, which should be caught with
+/// [`StrengthPolicy::BANNED_WORDS`].
+pub fn estimate_bits(raw: &str) -> u32 {
+    let pool = CharClasses::of(raw).pool_size();
+    if pool == 0 {
+        return 0;
+    }
+
+    // Bits per character, with ten fractional bits
+    let per_char = log2_fixed(pool);
+
+    let mut total = 0;
+    let mut prev: Option<(u32, Option<i64>)> = None;
+    for c in raw.chars() {
+        let c = u32::from(c);
+        let step = prev.map(|(p, _)| i64::from(c) - i64::from(p));
+        let patterned = match (prev, step) {
+            (Some((_, prev_step)), Some(step)) => {
+                step == 0 || (step.abs() == 1 && (prev_step.is_none() || prev_step == Some(step)))
+            }
+            _ => false,
+        };
+
+        total += if patterned { 1 << 10 } else { per_char };
+        prev = Some((c, step));
+    }
+
+    total >> 10
+}
+
+/// Computes `log2(x)` with ten fractional bits
+fn log2_fixed(x: u32) -> u32 {
+    const FRACTIONAL_BITS: u32 = 10;
+    const ONE: u64 = 1 << 32;
+
+    let int = 31 - x.leading_zeros();
+
+    // Normalize `x` into [1, 2), then square repeatedly to extract each fractional bit
+    let mut y = (u64::from(x) << 32) >> int;
+    let mut frac = 0;
+    for i in (0..FRACTIONAL_BITS).rev() {
+        y = ((u128::from(y) * u128::from(y)) >> 32) as u64;
+        if y >= 2 * ONE {
+            y >>= 1;
+            frac |= 1 << i;
+        }
+    }
+
+    (int << FRACTIONAL_BITS) | frac
+}
+
+fn contains_ignore_ascii_case(haystack: &str, needle: &str) -> bool {
+    let (haystack, needle) = (haystack.as_bytes(), needle.as_bytes());
+    !needle.is_empty()
+        && haystack
+            .windows(needle.len())
+            .any(|window| window.eq_ignore_ascii_case(needle))
+}
+
+/// A set of classes of characters
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CharClasses(u8);
+
+impl CharClasses {
+    /// No classes
+    pub const NONE: Self = Self(0);
+
+    /// The lowercase ASCII letters
+    pub const LOWERCASE: Self = Self(1 << 0);
+
+    /// The uppercase ASCII letters
+    pub const UPPERCASE: Self = Self(1 << 1);
+
+    /// The ASCII digits
+    pub const DIGIT: Self = Self(1 << 2);
+
+    /// ASCII punctuation and the space character
+    pub const SYMBOL: Self = Self(1 << 3);
+
+    /// Any other character, including ASCII control characters and non-ASCII characters
+    pub const OTHER: Self = Self(1 << 4);
+
+    const ALL: [(Self, &'static str, u32); 5] = [
+        (Self::LOWERCASE, "lowercase letters", 26),
+        (Self::UPPERCASE, "uppercase letters", 26),
+        (Self::DIGIT, "digits", 10),
+        (Self::SYMBOL, "symbols", 33),
+        (Self::OTHER, "other characters", 100),
+    ];
+
+    /// The classes of the characters in `raw`
+    pub fn of(raw: &str) -> Self {
+        raw.chars().fold(Self::NONE, |classes, c| {
+            let class = match c {
+                'a'..='z' => Self::LOWERCASE,
+                'A'..='Z' => Self::UPPERCASE,
+                '0'..='9' => Self::DIGIT,
+                ' ' => Self::SYMBOL,
+                c if c.is_ascii_punctuation() => Self::SYMBOL,
+                _ => Self::OTHER,
+            };
+            classes.union(class)
+        })
+    }
+
+    /// The classes in either set
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// The classes in this set but not in `other`
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Whether every class in `other` is in this set
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether the set is empty
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The number of classes in the set
+    pub const fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    fn pool_size(self) -> u32 {
+        Self::ALL
+            .iter()
+            .filter(|(class, ..)| self.contains(*class))
+            .map(|(_, _, size)| size)
+            .sum()
+    }
+}
+
+impl ops::BitOr for CharClasses {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl fmt::Display for CharClasses {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut names = Self::ALL
+            .iter()
+            .filter(|(class, ..)| self.contains(*class))
+            .map(|(_, name, _)| name);
+
+        match names.next() {
+            Some(first) => f.write_str(first)?,
+            None => return f.write_str("no characters"),
+        }
+        for name in names {
+            f.write_str(", ")?;
+            f.write_str(name)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for CharClasses {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CharClasses({self})")
+    }
+}
+
+/// The error produced when [`Strength`] rejects a value
+///
+/// The error never includes the value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StrengthError {
+    /// The value contains one of the [banned words][StrengthPolicy::BANNED_WORDS]
+    BannedWord,
+
+    /// The value lacks characters from these [required classes][StrengthPolicy::REQUIRED_CLASSES]
+    MissingClasses(CharClasses),
+
+    /// The value has characters from too few [classes][StrengthPolicy::MIN_CLASSES]
+    TooFewClasses {
+        /// The number of classes in the value
+        found: u32,
+        /// The minimum number of classes required
+        required: u32,
+    },
+
+    /// The [estimated entropy][estimate_bits] of the value is below the
+    /// [minimum][StrengthPolicy::MIN_BITS]
+    TooWeak {
+        /// The estimated entropy of the value, in bits
+        estimated_bits: u32,
+        /// The minimum entropy required, in bits
+        min_bits: u32,
+    },
+}
+
+impl fmt::Display for StrengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::BannedWord => f.write_str("value contains a banned word"),
+            Self::MissingClasses(missing) => write!(f, "value must contain {missing}"),
+            Self::TooFewClasses { found, required } => write!(
+                f,
+                "value must contain at least {required} classes of characters, but has {found}"
+            ),
+            Self::TooWeak {
+                estimated_bits,
+                min_bits,
+            } => write!(
+                f,
+                "value is too weak: estimated {estimated_bits} bits of entropy, but at least \
+                 {min_bits} are required"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StrengthError {}
+
+crate::from_infallible!(StrengthError);
@@ -0,0 +1,217 @@
+use crate::{ValidationError, Validator};
+
+/// Forwards the details of the rejecting braid to a [`ValidationError`]
+macro_rules! annotate_error {
+    () => {
+        #[inline]
+        fn annotate_error(
+            error: crate::ValidationError,
+            type_name: &'static str,
+            secret: bool,
+        ) -> crate::ValidationError {
+            error.for_braid(type_name, secret)
+        }
+    };
+}
+
+pub(super) use annotate_error;
+
+/// Rejects empty values
+///
+/// ```
+/// use aliri_braid::{braid, validators::NonEmpty};
+///
+/// #[braid(validator = "NonEmpty")]
+/// pub struct Hostname;
+///
+/// assert!(Hostname::new("localhost".to_owned()).is_ok());
+///
+/// let err = Hostname::new(String::new()).unwrap_err();
+/// assert_eq!("non_empty", err.rule());
+/// assert_eq!("invalid `Hostname`: value must not be empty", err.to_string());
+/// ```
+#[derive(Debug)]
+pub struct NonEmpty;
+
+impl Validator for NonEmpty {
+    type Error = ValidationError;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.is_empty() {
+            Err(ValidationError::new(
+                "non_empty",
+                "value must not be empty",
+                0,
+                0,
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    annotate_error!();
+}
+
+/// Rejects values longer than `N` bytes
+///
+/// The span of the error covers the bytes beyond the first `N`.
+#[derive(Debug)]
+pub struct MaxLen<const N: usize>;
+
+impl<const N: usize> Validator for MaxLen<N> {
+    type Error = ValidationError;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.len() > N {
+            Err(ValidationError::new(
+                "max_len",
+                "value is longer than the maximum length",
+                N,
+                raw.len() - N,
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    annotate_error!();
+}
+
+/// Rejects values shorter than `N` bytes
+///
+/// The span of the error is empty, and located at the end of the value.
+#[derive(Debug)]
+pub struct MinLen<const N: usize>;
+
+impl<const N: usize> Validator for MinLen<N> {
+    type Error = ValidationError;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.len() < N {
+            Err(ValidationError::new(
+                "min_len",
+                "value is shorter than the minimum length",
+                raw.len(),
+                0,
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    annotate_error!();
+}
+
+/// Finds the first character in `raw` that does not satisfy `allowed`, as an error whose span
+/// covers that character
+pub(super) fn find_char(
+    raw: &str,
+    allowed: impl Fn(char) -> bool,
+    rule: &'static str,
+    message: &'static str,
+) -> Result<(), ValidationError> {
+    match raw.char_indices().find(|&(_, ch)| !allowed(ch)) {
+        Some((offset, ch)) => Err(ValidationError::new(rule, message, offset, ch.len_utf8())),
+        None => Ok(()),
+    }
+}
+
+macro_rules! char_validator {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $rule:literal, $allowed:expr, $message:literal
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug)]
+        pub struct $name;
+
+        impl Validator for $name {
+            type Error = ValidationError;
+
+            fn validate(raw: &str) -> Result<(), Self::Error> {
+                find_char(raw, $allowed, $rule, $message)
+            }
+
+            annotate_error!();
+        }
+    };
+}
+
+char_validator! {
+    /// Rejects values containing non-ASCII characters
+    Ascii,
+    "ascii",
+    |ch: char| ch.is_ascii(),
+    "value must only contain ASCII characters"
+}
+
+char_validator! {
+    /// Rejects values containing characters other than printable ASCII characters
+    ///
+    /// Printable characters are the ASCII graphic characters and the space character.
+    AsciiPrintable,
+    "ascii_printable",
+    |ch: char| ch == ' ' || ch.is_ascii_graphic(),
+    "value must only contain printable ASCII characters"
+}
+
+char_validator! {
+    /// Rejects values containing control characters
+    ///
+    /// Control characters are those in the Unicode `Cc` category, such as `\n`, `\t`, and
+    /// `\u{7f}`.
+    NoControlChars,
+    "no_control_chars",
+    |ch: char| !ch.is_control(),
+    "value must not contain control characters"
+}
+
+char_validator! {
+    /// Rejects values containing a NUL character
+    ///
+    /// Such values cannot be passed to APIs expecting C strings.
+    NoNul,
+    "no_nul",
+    |ch: char| ch != '\0',
+    "value must not contain NUL characters"
+}
+
+/// Rejects values with leading or trailing whitespace
+///
+/// Whitespace is as defined by [`char::is_whitespace()`]. The span of the error covers the
+/// leading whitespace, or the trailing whitespace if there is none leading. See
+/// [`TrimWhitespace`] for a normalizer that removes it instead.
+///
+/// [`TrimWhitespace`]: super::TrimWhitespace
+#[derive(Debug)]
+pub struct Trimmed;
+
+impl Validator for Trimmed {
+    type Error = ValidationError;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        let start = raw.trim_start();
+        if start.len() != raw.len() {
+            return Err(ValidationError::new(
+                "trimmed",
+                "value must not begin with whitespace",
+                0,
+                raw.len() - start.len(),
+            ));
+        }
+
+        let end = raw.trim_end();
+        if end.len() != raw.len() {
+            return Err(ValidationError::new(
+                "trimmed",
+                "value must not end with whitespace",
+                end.len(),
+                raw.len() - end.len(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    annotate_error!();
+}
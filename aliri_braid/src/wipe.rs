@@ -0,0 +1,24 @@
+use core::sync::atomic::{compiler_fence, Ordering};
+
+/// Overwrites the bytes with zeros in a way that will not be optimized away
+pub(crate) fn wipe(bytes: &mut [u8]) {
+    for b in bytes.iter_mut() {
+        #[allow(unsafe_code)]
+        // SAFETY: `b` is a valid, aligned, and exclusive reference
+        unsafe {
+            core::ptr::write_volatile(b, 0)
+        };
+    }
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// A buffer that is wiped when dropped
+#[cfg(feature = "std")]
+pub(crate) struct Scratch(pub(crate) std::vec::Vec<u8>);
+
+#[cfg(feature = "std")]
+impl Drop for Scratch {
+    fn drop(&mut self) {
+        wipe(&mut self.0);
+    }
+}
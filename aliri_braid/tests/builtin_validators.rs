@@ -0,0 +1,146 @@
+use std::borrow::Cow;
+
+use aliri_braid::{
+    braid, braid_ref,
+    validators::{
+        All, AllError, Ascii, AsciiPrintable, Lowercase, MaxLen, MinLen, NoControlChars, NoNul,
+        NonEmpty, TrimWhitespace, Trimmed,
+    },
+    Normalizer, ValidationError, Validator,
+};
+
+#[braid(validator = "All<(NonEmpty, AsciiPrintable, MaxLen<255>)>")]
+pub struct Subject;
+
+#[braid(normalizer = "Lowercase")]
+pub struct Email;
+
+#[braid(normalizer = "TrimWhitespace")]
+pub struct DisplayName;
+
+#[braid_ref(validator = "All<(MinLen<2>, NoNul)>", no_std)]
+pub struct Path;
+
+fn rejection<V: Validator<Error = ValidationError>>(raw: &str) -> (&'static str, usize, usize) {
+    let err = V::validate(raw).unwrap_err();
+    (err.rule(), err.span().start, err.span().end)
+}
+
+#[test]
+fn lengths_are_checked_in_bytes() {
+    assert!(NonEmpty::validate("a").is_ok());
+    assert_eq!(("non_empty", 0, 0), rejection::<NonEmpty>(""));
+
+    assert!(MaxLen::<3>::validate("abc").is_ok());
+    assert_eq!(("max_len", 3, 4), rejection::<MaxLen<3>>("abcd"));
+    assert_eq!(("max_len", 3, 4), rejection::<MaxLen<3>>("éé"));
+
+    assert!(MinLen::<2>::validate("é").is_ok());
+    assert_eq!(("min_len", 1, 1), rejection::<MinLen<2>>("a"));
+    assert_eq!(
+        "value is shorter than the minimum length",
+        MinLen::<2>::validate("a").unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn rejected_characters_are_located() {
+    assert!(Ascii::validate("tab\there").is_ok());
+    assert_eq!(("ascii", 3, 5), rejection::<Ascii>("caf\u{e9}"));
+
+    assert!(AsciiPrintable::validate("a b ~").is_ok());
+    assert_eq!(
+        ("ascii_printable", 3, 4),
+        rejection::<AsciiPrintable>("tab\there")
+    );
+    assert_eq!(("ascii_printable", 0, 2), rejection::<AsciiPrintable>("é"));
+
+    assert!(NoControlChars::validate("é ü").is_ok());
+    assert_eq!(
+        ("no_control_chars", 2, 3),
+        rejection::<NoControlChars>("é\u{7f}")
+    );
+
+    assert!(NoNul::validate("nul").is_ok());
+    assert_eq!(("no_nul", 5, 6), rejection::<NoNul>("nul\u{e9}\0"));
+}
+
+#[test]
+fn surrounding_whitespace_is_rejected() {
+    assert!(Trimmed::validate("a b").is_ok());
+    assert!(Trimmed::validate("").is_ok());
+    assert_eq!(("trimmed", 0, 2), rejection::<Trimmed>(" \ta"));
+    assert_eq!(("trimmed", 1, 5), rejection::<Trimmed>("a\u{a0}\n "));
+    assert_eq!(
+        "value must not end with whitespace",
+        Trimmed::validate("a ").unwrap_err().to_string()
+    );
+    assert_eq!(Trimmed::validate(" a"), TrimWhitespace::validate(" a"));
+}
+
+#[test]
+fn combined_validators_report_the_rule_that_failed() {
+    assert!(Subject::new("Hello, world!".to_owned()).is_ok());
+
+    let err = Subject::new(String::new()).unwrap_err();
+    let AllError::First(err) = err else {
+        panic!("unexpected error: {:?}", err);
+    };
+    assert_eq!("non_empty", err.rule());
+    assert_eq!(Some("Subject"), err.type_name());
+
+    assert!(matches!(
+        Subject::new("tab\t".to_owned()),
+        Err(AllError::Second(_))
+    ));
+    assert!(matches!(
+        Subject::new("a".repeat(256)),
+        Err(AllError::Third(_))
+    ));
+
+    assert!(Path::from_str("/a").is_ok());
+    assert!(matches!(Path::from_str("/"), Err(AllError::First(_))));
+    assert!(matches!(Path::from_str("/\0"), Err(AllError::Second(_))));
+}
+
+#[test]
+fn lowercase_normalizes_unicode() {
+    assert_eq!(
+        "alice@example.com",
+        Email::new("Alice@Example.COM".to_owned()).unwrap().as_str()
+    );
+    assert_eq!("straße", Email::from_static("STRAßE").as_str());
+    assert!(matches!(
+        Lowercase::normalize("already"),
+        Ok(Cow::Borrowed("already"))
+    ));
+
+    let err = EmailRef::from_normalized_str("bob@Example.com").unwrap_err();
+    assert_eq!(("lowercase", 4..5), (err.rule(), err.span()));
+    assert_eq!(
+        "invalid `EmailRef`: value must be lowercase",
+        err.to_string()
+    );
+}
+
+#[test]
+fn trim_whitespace_normalizes_without_allocating() {
+    assert_eq!(
+        "Alice",
+        DisplayName::new("  Alice\n".to_owned()).unwrap().as_str()
+    );
+    assert!(matches!(
+        TrimWhitespace::normalize(" Alice "),
+        Ok(Cow::Borrowed("Alice"))
+    ));
+    assert!(matches!(
+        DisplayNameRef::from_str(" Alice "),
+        Ok(Cow::Borrowed(name)) if name.as_str() == "Alice"
+    ));
+    assert_eq!(
+        5..6,
+        DisplayNameRef::from_normalized_str("Alice ")
+            .unwrap_err()
+            .span()
+    );
+}
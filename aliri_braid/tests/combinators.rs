@@ -0,0 +1,175 @@
+use std::{error::Error, fmt};
+
+use aliri_braid::{
+    braid, braid_ref,
+    validators::{All, AllError, Any, AnyError, Map, Not, NotError},
+    Validator,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Rejected(&'static str);
+
+impl fmt::Display for Rejected {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl Error for Rejected {}
+
+aliri_braid::from_infallible!(Rejected);
+
+pub struct NonEmpty;
+
+impl Validator for NonEmpty {
+    type Error = Rejected;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.is_empty() {
+            Err(Rejected("value must not be empty"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+pub struct Digits;
+
+impl Validator for Digits {
+    type Error = Rejected;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.bytes().all(|b| b.is_ascii_digit()) {
+            Ok(())
+        } else {
+            Err(Rejected("value must only contain digits"))
+        }
+    }
+}
+
+pub struct Short;
+
+impl Validator for Short {
+    type Error = Rejected;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.len() <= 4 {
+            Ok(())
+        } else {
+            Err(Rejected("value must be at most 4 bytes long"))
+        }
+    }
+}
+
+#[braid(validator = "All<(NonEmpty, Digits, Short)>")]
+pub struct Pin;
+
+#[braid(validator = "Any<(Digits, Short)>")]
+pub struct Code;
+
+#[braid(validator = "All<(NonEmpty, Not<Digits>)>")]
+pub struct Handle;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct InvalidTicket(String);
+
+impl fmt::Display for InvalidTicket {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid ticket: {}", self.0)
+    }
+}
+
+impl Error for InvalidTicket {}
+
+aliri_braid::from_infallible!(InvalidTicket);
+
+impl<E0: fmt::Display, E1: fmt::Display> From<AllError<E0, E1>> for InvalidTicket {
+    fn from(err: AllError<E0, E1>) -> Self {
+        Self(err.to_string())
+    }
+}
+
+#[braid(validator = "Map<All<(NonEmpty, Digits)>, InvalidTicket>")]
+pub struct Ticket;
+
+#[braid_ref(validator = "All<(NonEmpty, Short)>")]
+pub struct Tag;
+
+static_assertions::assert_impl_all!(
+    AllError<Rejected, Rejected, Rejected>: Error, From<std::convert::Infallible>
+);
+static_assertions::assert_impl_all!(
+    AnyError<(Rejected, Rejected)>: Error, From<std::convert::Infallible>
+);
+static_assertions::assert_impl_all!(NotError: Error, From<std::convert::Infallible>);
+
+#[test]
+fn all_accepts_values_accepted_by_every_validator() {
+    assert!(Pin::new("1234".to_owned()).is_ok());
+    assert!(Tag::from_str("rust").is_ok());
+}
+
+#[test]
+fn all_reports_the_first_validator_to_reject() {
+    let err = Pin::new(String::new()).unwrap_err();
+    assert_eq!(AllError::First(Rejected("value must not be empty")), err);
+    assert_eq!(0, err.index());
+
+    let err = Pin::new("12a".to_owned()).unwrap_err();
+    assert_eq!(
+        AllError::Second(Rejected("value must only contain digits")),
+        err
+    );
+    assert_eq!("value must only contain digits", err.to_string());
+
+    let err = Pin::new("12345".to_owned()).unwrap_err();
+    assert_eq!(2, err.index());
+
+    assert!(matches!(Tag::from_str("golang"), Err(AllError::Second(_))));
+}
+
+#[test]
+fn any_accepts_values_accepted_by_some_validator() {
+    assert!(Code::new("123456".to_owned()).is_ok());
+    assert!(Code::new("ab".to_owned()).is_ok());
+}
+
+#[test]
+fn any_reports_every_rejection() {
+    let err = Code::new("abcdef".to_owned()).unwrap_err();
+    assert_eq!(
+        AnyError((
+            Rejected("value must only contain digits"),
+            Rejected("value must be at most 4 bytes long"),
+        )),
+        err
+    );
+    assert_eq!(
+        "value was rejected by every alternative; value must only contain digits; value must be \
+         at most 4 bytes long",
+        err.to_string()
+    );
+}
+
+#[test]
+fn not_inverts_a_validator() {
+    assert!(Handle::new("alice".to_owned()).is_ok());
+
+    let err = Handle::new("1234".to_owned()).unwrap_err();
+    let AllError::Second(not) = err else {
+        panic!("unexpected error: {:?}", err);
+    };
+    assert!(not.validator().ends_with("Digits"));
+    assert!(not
+        .to_string()
+        .starts_with("value must not be accepted by `"));
+}
+
+#[test]
+fn map_converts_the_error() {
+    assert!(Ticket::new("42".to_owned()).is_ok());
+    assert_eq!(
+        InvalidTicket("value must only contain digits".to_owned()),
+        Ticket::new("4x2".to_owned()).unwrap_err()
+    );
+}
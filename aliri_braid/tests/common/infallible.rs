@@ -82,6 +82,7 @@ pub fn from_owned_pass() {
 }
 
 #[test]
+#[allow(clippy::unnecessary_fallible_conversions)]
 pub fn try_from_owned_pass() -> Result<(), Box<dyn std::error::Error>> {
     let x: Orange = "One".try_into()?;
     assert_eq!("One", x.as_str());
@@ -89,6 +90,7 @@ pub fn try_from_owned_pass() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 #[test]
+#[allow(clippy::unnecessary_fallible_conversions)]
 pub fn try_from_borrowed_pass() -> Result<(), Box<dyn std::error::Error>> {
     let x: &OrangeRef = "One".try_into()?;
     assert_eq!("One", x.as_str());
@@ -218,7 +220,7 @@ fn check_reference_size() {
 }
 
 #[test]
-#[allow(clippy::forget_ref, clippy::transmute_ptr_to_ptr)]
+#[allow(forgetting_references, clippy::transmute_ptr_to_ptr)]
 fn check_reference_size_ptr() {
     let s = "source";
     let y: &OrangeRef = OrangeRef::from_str(s);
@@ -226,7 +228,7 @@ fn check_reference_size_ptr() {
 }
 
 #[test]
-#[allow(clippy::forget_ref, clippy::transmute_ptr_to_ptr)]
+#[allow(forgetting_references, clippy::transmute_ptr_to_ptr)]
 fn check_reference_size_val() {
     let s = "source";
     let y: &OrangeRef = OrangeRef::from_str(s);
@@ -315,7 +315,7 @@ fn check_reference_size() {
 }
 
 #[test]
-#[allow(clippy::forget_ref, clippy::transmute_ptr_to_ptr)]
+#[allow(forgetting_references, clippy::transmute_ptr_to_ptr)]
 fn check_reference_size_ptr() {
     let s = "source";
     let y: &Normalized = &Normalized::from_str(s).unwrap();
@@ -323,7 +323,7 @@ fn check_reference_size_ptr() {
 }
 
 #[test]
-#[allow(clippy::forget_ref, clippy::transmute_ptr_to_ptr)]
+#[allow(forgetting_references, clippy::transmute_ptr_to_ptr)]
 fn check_reference_size_ptr_normalized() {
     let s = "source five";
     let y: &Normalized = &Normalized::from_str(s).unwrap();
@@ -331,7 +331,7 @@ fn check_reference_size_ptr_normalized() {
 }
 
 #[test]
-#[allow(clippy::forget_ref, clippy::transmute_ptr_to_ptr)]
+#[allow(forgetting_references, clippy::transmute_ptr_to_ptr)]
 fn check_reference_size_val() {
     let s = "source";
     let y: &Normalized = &Normalized::from_str(s).unwrap();
@@ -341,7 +341,7 @@ fn check_reference_size_val() {
 }
 
 #[test]
-#[allow(clippy::forget_ref, clippy::transmute_ptr_to_ptr)]
+#[allow(forgetting_references, clippy::transmute_ptr_to_ptr)]
 fn check_reference_size_val_normalized() {
     let s = "source five";
     let y: &Normalized = &Normalized::from_str(s).unwrap();
@@ -0,0 +1,75 @@
+use std::{error, fmt};
+
+use aliri_braid::{braid, braid_ref, Validator};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct InvalidUsername(&'static str);
+
+impl fmt::Display for InvalidUsername {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl error::Error for InvalidUsername {}
+
+aliri_braid::from_infallible!(InvalidUsername);
+
+#[braid(validator, const_validator)]
+pub struct Username;
+
+impl Username {
+    pub const fn validate_const(raw: &str) -> Result<(), &'static str> {
+        let bytes = raw.as_bytes();
+        if bytes.is_empty() {
+            return Err("username cannot be empty");
+        }
+
+        let mut i = 0;
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_lowercase() {
+                return Err("username must only contain lowercase ASCII letters");
+            }
+            i += 1;
+        }
+
+        Ok(())
+    }
+}
+
+impl Validator for Username {
+    type Error = InvalidUsername;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        Self::validate_const(raw).map_err(InvalidUsername)
+    }
+}
+
+#[braid_ref(validator = "Username", const_validator)]
+pub struct Login;
+
+const ADMIN: &UsernameRef = UsernameRef::from_static("admin");
+const ROOT: &Login = Login::from_static("root");
+
+#[test]
+fn from_static_is_const() {
+    assert_eq!("admin", ADMIN.as_str());
+    assert_eq!("root", ROOT.as_str());
+}
+
+#[test]
+fn runtime_validation_is_unchanged() {
+    assert_eq!(
+        Err(InvalidUsername("username cannot be empty")),
+        UsernameRef::from_str("").map(|_| ())
+    );
+    assert!(Login::from_str("Root").is_err());
+    assert!(Username::new("admin".to_owned()).is_ok());
+}
+
+#[test]
+#[should_panic(expected = "username must only contain lowercase ASCII letters")]
+fn from_static_panics_at_runtime_outside_const() {
+    let value: &'static str = Box::leak(String::from("Admin").into_boxed_str());
+    UsernameRef::from_static(value);
+}
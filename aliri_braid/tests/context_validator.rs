@@ -0,0 +1,191 @@
+use std::collections::HashSet;
+
+use aliri_braid::{
+    braid, braid_ref,
+    validators::{AsciiPrintable, NonEmpty},
+    ContextValidator, ValidationError, Validator,
+};
+use serde::de::DeserializeSeed;
+
+pub struct TenantConfig {
+    tenants: HashSet<&'static str>,
+}
+
+impl TenantConfig {
+    fn new(tenants: &[&'static str]) -> Self {
+        Self {
+            tenants: tenants.iter().copied().collect(),
+        }
+    }
+}
+
+#[braid(serde, validator, context_validator)]
+pub struct Tenant;
+
+impl Validator for Tenant {
+    type Error = ValidationError;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        NonEmpty::validate(raw)
+    }
+
+    fn annotate_error(error: Self::Error, type_name: &'static str, secret: bool) -> Self::Error {
+        error.for_braid(type_name, secret)
+    }
+}
+
+impl ContextValidator for Tenant {
+    type Context = TenantConfig;
+
+    fn validate_with(context: &Self::Context, raw: &str) -> Result<(), Self::Error> {
+        if context.tenants.contains(raw) {
+            Ok(())
+        } else {
+            Err(ValidationError::new(
+                "known_tenant",
+                "value must be a configured tenant",
+                0,
+                raw.len(),
+            ))
+        }
+    }
+}
+
+pub struct Prefixed;
+
+impl Validator for Prefixed {
+    type Error = ValidationError;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        AsciiPrintable::validate(raw)
+    }
+}
+
+impl ContextValidator for Prefixed {
+    type Context = str;
+
+    fn validate_with(prefix: &str, raw: &str) -> Result<(), Self::Error> {
+        if raw.starts_with(prefix) {
+            Ok(())
+        } else {
+            Err(ValidationError::new(
+                "prefix",
+                "value must begin with the prefix",
+                0,
+                0,
+            ))
+        }
+    }
+}
+
+#[braid(secret, serde = "expose", validator = "Prefixed", context_validator)]
+pub struct ApiKey;
+
+#[braid_ref(validator = "Prefixed", context_validator)]
+pub struct Route;
+
+#[test]
+fn context_free_constructors_skip_context_rules() {
+    assert!(Tenant::new("globex".to_owned()).is_ok());
+    assert!(TenantRef::from_str("globex").is_ok());
+    assert!(Tenant::new(String::new()).is_err());
+}
+
+#[test]
+fn constructors_check_both_sets_of_rules() {
+    let config = TenantConfig::new(&["acme", "initech"]);
+
+    assert_eq!(
+        "acme",
+        Tenant::new_with(&config, "acme".to_owned())
+            .unwrap()
+            .as_str()
+    );
+    assert_eq!(
+        "acme",
+        TenantRef::from_str_with(&config, "acme").unwrap().as_str()
+    );
+
+    let err = Tenant::new_with(&config, "globex".to_owned()).unwrap_err();
+    assert_eq!("known_tenant", err.rule());
+    assert_eq!(Some("Tenant"), err.type_name());
+
+    let err = TenantRef::from_str_with(&config, "").unwrap_err();
+    assert_eq!("non_empty", err.rule());
+    assert_eq!(Some("TenantRef"), err.type_name());
+}
+
+#[test]
+fn unsized_contexts_are_supported() {
+    assert!(Route::from_str_with("/api/", "/api/users").is_ok());
+    assert_eq!(
+        "prefix",
+        Route::from_str_with("/api/", "/admin").unwrap_err().rule()
+    );
+    assert!(Route::from_str("/admin").is_ok());
+}
+
+#[test]
+fn secret_errors_are_redacted() {
+    assert!(ApiKey::new_with("sk_", "sk_live".to_owned()).is_ok());
+
+    let err = ApiKey::new_with("sk_", "pk_live".to_owned()).unwrap_err();
+    assert!(!err.to_string().contains("pk_live"));
+    assert_eq!("prefix", err.get_ref().rule());
+}
+
+#[test]
+fn edits_are_checked_against_the_context() {
+    let config = TenantConfig::new(&["acme", "initech"]);
+    let mut tenant = Tenant::new_with(&config, "acme".to_owned()).unwrap();
+
+    tenant
+        .edit_with(&config, |inner| *inner = "initech".to_owned())
+        .unwrap();
+    assert_eq!("initech", tenant.as_str());
+
+    let err = tenant
+        .edit_with(&config, |inner| *inner = "globex".to_owned())
+        .unwrap_err();
+    assert_eq!("known_tenant", err.rule());
+    assert_eq!("initech", tenant.as_str());
+
+    let mut key = ApiKey::new_with("sk_", "sk_live".to_owned()).unwrap();
+    assert!(key
+        .edit_with("sk_", |inner| inner.replace_range(..2, "pk"))
+        .is_err());
+    assert!(key.edit_with("sk_", |inner| inner.push_str("_2")).is_ok());
+}
+
+#[test]
+fn seed_deserializes_with_context() {
+    let config = TenantConfig::new(&["acme"]);
+
+    let tenant = TenantSeed(&config)
+        .deserialize(&mut serde_json::Deserializer::from_str(r#""acme""#))
+        .unwrap();
+    assert_eq!("acme", tenant.as_str());
+
+    let err = TenantSeed(&config)
+        .deserialize(&mut serde_json::Deserializer::from_str(r#""globex""#))
+        .unwrap_err();
+    assert_eq!(
+        "invalid `Tenant`: value must be a configured tenant",
+        err.to_string()
+    );
+
+    let err = TenantSeed(&config)
+        .deserialize(&mut serde_json::Deserializer::from_str(r#""""#))
+        .unwrap_err();
+    assert_eq!("invalid `Tenant`: value must not be empty", err.to_string());
+
+    assert!(serde_json::from_str::<Tenant>(r#""globex""#).is_ok());
+}
+
+#[test]
+fn seed_redacts_secret_errors() {
+    let err = ApiKeySeed("sk_")
+        .deserialize(&mut serde_json::Deserializer::from_str(r#""pk_live""#))
+        .unwrap_err();
+    assert!(!err.to_string().contains("pk_live"));
+}
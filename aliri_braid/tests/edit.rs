@@ -0,0 +1,107 @@
+use aliri_braid::{
+    braid,
+    validators::{Lowercase, MaxLen, NonEmpty},
+    SecretAccess, SecretAccessHook, ValidationError,
+};
+
+#[braid(validate(non_empty, max_len = 16, charset = "a-z:"))]
+pub struct Arn;
+
+impl Arn {
+    pub fn add_segment(&mut self, segment: &str) -> Result<(), InvalidArn> {
+        self.edit(|inner| {
+            inner.push(':');
+            inner.push_str(segment);
+        })
+    }
+}
+
+#[braid(normalizer = "Lowercase")]
+pub struct Email;
+
+thread_local! {
+    static ACCESSES: std::cell::RefCell<Vec<(&'static str, SecretAccess)>> = Default::default();
+}
+
+pub struct RecordAccess;
+
+impl SecretAccessHook for RecordAccess {
+    fn on_access(type_name: &'static str, access: SecretAccess) {
+        ACCESSES.with(|a| a.borrow_mut().push((type_name, access)));
+    }
+}
+
+#[braid(
+    secret(audit = "RecordAccess", zeroize, strict),
+    validator = "MaxLen<8>"
+)]
+pub struct Pin;
+
+#[braid(secret(zeroize), validator = "NonEmpty")]
+pub struct Token;
+
+#[test]
+fn edits_are_revalidated() {
+    let mut arn = Arn::from_static("arn:aws");
+    arn.add_segment("iam").unwrap();
+    assert_eq!("arn:aws:iam", arn.as_str());
+
+    assert_eq!(
+        InvalidArn::InvalidChar {
+            ch: 'I',
+            offset: 12
+        },
+        arn.add_segment("IAM").unwrap_err()
+    );
+    assert_eq!("arn:aws:iam", arn.as_str());
+
+    assert_eq!(
+        InvalidArn::TooLong { len: 17 },
+        arn.add_segment("users").unwrap_err()
+    );
+    assert_eq!("arn:aws:iam", arn.as_str());
+}
+
+#[test]
+fn edits_are_renormalized() {
+    let mut email = Email::from_static("alice@example.com");
+    email.edit(|inner| inner.replace_range(..5, "Bob")).unwrap();
+    assert_eq!("bob@example.com", email.as_str());
+}
+
+#[test]
+fn panicking_edits_keep_the_previous_value() {
+    let mut token = Token::from_static("hunter2");
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        token
+            .edit(|inner| {
+                inner.clear();
+                panic!("edit failed");
+            })
+            .unwrap();
+    }));
+    assert!(result.is_err());
+    assert_eq!("hunter2", token.as_str());
+
+    let err: ValidationError = token.edit(String::clear).unwrap_err().into_inner();
+    assert_eq!("non_empty", err.rule());
+    assert_eq!(Some("Token"), err.type_name());
+    assert_eq!("hunter2", token.as_str());
+}
+
+#[test]
+fn edits_of_secrets_are_audited() {
+    let mut pin = Pin::from_static("1234");
+    pin.edit(|inner| inner.push_str("5678")).unwrap();
+    assert!(pin.edit(|inner| inner.push('9')).is_err());
+    assert_eq!("12345678", pin.expose_secret());
+
+    assert_eq!(
+        vec![
+            ("Pin", SecretAccess::Edit),
+            ("Pin", SecretAccess::Edit),
+            ("PinRef", SecretAccess::AsStr),
+        ],
+        ACCESSES.with(|a| a.take())
+    );
+}
@@ -0,0 +1,61 @@
+use aliri_braid::{braid, braid_ref, redaction};
+
+#[braid(secret(fingerprint))]
+pub struct ApiKey;
+
+#[braid(secret(debug(fingerprint), display(length_only)))]
+pub struct SessionToken;
+
+#[braid_ref(secret(fingerprint))]
+pub struct ApiKeyStr;
+
+// The fingerprint key is process-wide and can only be set once, so the behavior before
+// and after it is set is checked in sequence within a single test.
+#[test]
+fn fingerprints_are_keyed() {
+    let key = ApiKey::from_static("hunter2");
+    assert_eq!(None, key.fingerprint());
+    assert_eq!("[redacted ApiKey]", format!("{:?}", key));
+    assert_eq!("[redacted ApiKeyRef]", format!("{}", &*key));
+
+    redaction::set_fingerprint_key(*b"0123456789abcdef").unwrap();
+    assert_eq!(
+        Err(redaction::FingerprintKeyAlreadySet),
+        redaction::set_fingerprint_key([0; 16])
+    );
+
+    let fingerprint = key.fingerprint().unwrap().to_string();
+    assert_eq!(8, fingerprint.len());
+    assert!(fingerprint.bytes().all(|b| b.is_ascii_hexdigit()));
+    assert_eq!(
+        format!("[redacted ApiKey#{fingerprint}]"),
+        format!("{:?}", key)
+    );
+    assert_eq!(
+        format!("[redacted ApiKey#{fingerprint}]"),
+        format!("{}", key)
+    );
+    assert_eq!(
+        format!("[redacted ApiKeyRef#{fingerprint}]"),
+        format!("{:?}", &*key)
+    );
+    assert_eq!("hunter2", format!("{:#}", key));
+
+    let same = ApiKeyStr::from_str("hunter2");
+    assert_eq!(key.fingerprint(), same.fingerprint());
+    assert_eq!(
+        format!("[redacted ApiKeyStr#{fingerprint}]"),
+        format!("{:?}", same)
+    );
+
+    let other = ApiKey::from_static("hunter3");
+    assert_ne!(key.fingerprint(), other.fingerprint());
+
+    let token = SessionToken::from_static("hunter2");
+    assert_eq!(key.fingerprint(), token.fingerprint());
+    assert_eq!(
+        format!("[redacted SessionToken#{fingerprint}]"),
+        format!("{:?}", token)
+    );
+    assert_eq!("[redacted, 7 chars]", format!("{}", token));
+}
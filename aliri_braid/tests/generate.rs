@@ -0,0 +1,190 @@
+use std::{
+    borrow::Cow,
+    collections::BTreeSet,
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use aliri_braid::{
+    braid,
+    generate::{
+        rand_core::{self, CryptoRng, RngCore},
+        Alphabet, MAX_ATTEMPTS,
+    },
+    Normalizer, Validator,
+};
+
+#[braid(secret, generate(len = 32, alphabet = "base62"))]
+pub struct ApiKey;
+
+#[braid(secret(zeroize), generate(len = 16, alphabet = "hex"), validator)]
+pub struct SessionId;
+
+#[derive(Debug)]
+pub struct InvalidSessionId;
+
+impl fmt::Display for InvalidSessionId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("session IDs must start with a letter")
+    }
+}
+
+aliri_braid::from_infallible!(InvalidSessionId);
+
+impl Validator for SessionId {
+    type Error = InvalidSessionId;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            Ok(())
+        } else {
+            Err(InvalidSessionId)
+        }
+    }
+}
+
+#[braid(generate(len = 8, alphabet = "base32"), normalizer)]
+pub struct InviteCode;
+
+#[derive(Debug)]
+pub struct InvalidInviteCode;
+
+aliri_braid::from_infallible!(InvalidInviteCode);
+
+impl Validator for InviteCode {
+    type Error = InvalidInviteCode;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.bytes().any(|b| b.is_ascii_uppercase()) {
+            Err(InvalidInviteCode)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Normalizer for InviteCode {
+    fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+        Ok(Cow::Owned(raw.to_ascii_lowercase()))
+    }
+}
+
+/// A seeded SplitMix64 generator, which is only marked cryptographically secure for testing
+struct SeededRng(u64);
+
+impl RngCore for SeededRng {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        for chunk in dst.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dst);
+        Ok(())
+    }
+}
+
+impl CryptoRng for SeededRng {}
+
+#[test]
+fn generates_from_os_rng() {
+    let key = ApiKey::generate();
+    assert_eq!(32, key.as_str().len());
+    assert!(key.as_str().bytes().all(|b| b.is_ascii_alphanumeric()));
+    assert_ne!(key, ApiKey::generate());
+}
+
+#[test]
+fn generated_secrets_are_redacted() {
+    let key = ApiKey::generate();
+    assert_eq!("[redacted ApiKey]", format!("{key:?}"));
+}
+
+#[test]
+fn seeded_generation_is_deterministic() {
+    let a = ApiKey::generate_with(&mut SeededRng(42));
+    let b = ApiKey::generate_with(&mut SeededRng(42));
+    let c = ApiKey::generate_with(&mut SeededRng(43));
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+}
+
+#[test]
+fn generated_values_use_whole_alphabet() {
+    let value = aliri_braid::generate::generate_with(&mut SeededRng(7), 4096, Alphabet::BASE62);
+    let symbols = value.bytes().collect::<BTreeSet<_>>();
+    let expected = Alphabet::BASE62.symbols().bytes().collect::<BTreeSet<_>>();
+    assert_eq!(expected, symbols);
+}
+
+#[test]
+fn generated_values_pass_validator() {
+    let mut rng = SeededRng(0);
+    for _ in 0..100 {
+        let id = SessionId::generate_with(&mut rng).unwrap();
+        assert_eq!(16, id.as_str().len());
+        assert!(SessionId::validate(id.as_str()).is_ok());
+        assert!(id.as_str().bytes().all(|b| b.is_ascii_hexdigit()));
+    }
+}
+
+#[test]
+fn generated_values_are_normalized() {
+    let code = InviteCode::generate().unwrap();
+    assert_eq!(8, code.as_str().len());
+    assert_eq!(code.as_str().to_ascii_lowercase(), code.as_str());
+}
+
+#[braid(generate(len = 4, alphabet = "digits"), validator)]
+pub struct NeverValid;
+
+static NEVER_VALID_ATTEMPTS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Rejected;
+
+aliri_braid::from_infallible!(Rejected);
+
+impl Validator for NeverValid {
+    type Error = Rejected;
+
+    fn validate(_: &str) -> Result<(), Self::Error> {
+        NEVER_VALID_ATTEMPTS.fetch_add(1, Ordering::Relaxed);
+        Err(Rejected)
+    }
+}
+
+#[test]
+fn rejected_values_are_retried_a_bounded_number_of_times() {
+    let err = NeverValid::generate_with(&mut SeededRng(1)).unwrap_err();
+    assert_eq!(Rejected, err);
+    assert_eq!(MAX_ATTEMPTS, NEVER_VALID_ATTEMPTS.load(Ordering::Relaxed));
+}
+
+#[test]
+fn custom_alphabets_are_supported() {
+    const VOWELS: Alphabet = Alphabet::new("aeiou");
+    let value = aliri_braid::generate::generate_with(&mut SeededRng(3), 64, VOWELS);
+    assert_eq!(64, value.len());
+    assert!(value.bytes().all(|b| b"aeiou".contains(&b)));
+}
+
+#[test]
+#[should_panic(expected = "alphabet must not repeat characters")]
+fn alphabets_reject_repeated_characters() {
+    Alphabet::new("abca");
+}
@@ -88,7 +88,7 @@ impl aliri_braid::Validator for NormalizedBuf {
 }
 
 impl aliri_braid::Normalizer for NormalizedBuf {
-    fn normalize(s: &str) -> Result<Cow<str>, Self::Error> {
+    fn normalize(s: &str) -> Result<Cow<'_, str>, Self::Error> {
         if s.chars().any(|c| c.len_utf8() > 3) {
             Err(InvalidData)
         } else if s.contains(' ') {
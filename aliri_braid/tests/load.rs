@@ -0,0 +1,143 @@
+use std::{error::Error, fmt, path::PathBuf};
+
+use aliri_braid::{
+    braid,
+    load::{LoadErrorKind, Origin},
+    Validator,
+};
+
+#[braid(secret(load))]
+pub struct ApiKey;
+
+#[braid(secret(load, zeroize), validator)]
+pub struct ClientSecret;
+
+#[derive(Debug)]
+pub struct InvalidClientSecret(String);
+
+impl fmt::Display for InvalidClientSecret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid client secret: {}", self.0)
+    }
+}
+
+impl Error for InvalidClientSecret {}
+
+aliri_braid::from_infallible!(InvalidClientSecret);
+
+impl Validator for ClientSecret {
+    type Error = InvalidClientSecret;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.starts_with("cs_") {
+            Ok(())
+        } else {
+            Err(InvalidClientSecret(raw.to_owned()))
+        }
+    }
+}
+
+fn temp_file(name: &str, contents: &[u8]) -> PathBuf {
+    let path =
+        std::env::temp_dir().join(format!("aliri_braid_load_{}_{}", std::process::id(), name));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+#[cfg_attr(
+    miri,
+    ignore = "miri isolation prevents file system and environment access"
+)]
+#[test]
+fn loads_from_env() {
+    std::env::set_var("ALIRI_BRAID_TEST_API_KEY", "hunter2\n");
+    let key = ApiKey::from_env("ALIRI_BRAID_TEST_API_KEY").unwrap();
+    assert_eq!("hunter2\n", key.as_str());
+}
+
+#[cfg_attr(
+    miri,
+    ignore = "miri isolation prevents file system and environment access"
+)]
+#[test]
+fn missing_env_var_is_reported() {
+    let err = ApiKey::from_env("ALIRI_BRAID_TEST_MISSING").unwrap_err();
+    assert!(matches!(err.kind(), LoadErrorKind::NotPresent));
+    assert_eq!("ApiKey", err.type_name());
+    assert_eq!(
+        &Origin::Env("ALIRI_BRAID_TEST_MISSING".to_owned()),
+        err.origin()
+    );
+    assert_eq!(
+        "failed to load `ApiKey` from environment variable `ALIRI_BRAID_TEST_MISSING`: not present",
+        err.to_string()
+    );
+}
+
+#[cfg_attr(
+    miri,
+    ignore = "miri isolation prevents file system and environment access"
+)]
+#[test]
+fn loads_from_file_without_line_ending() {
+    let path = temp_file("crlf", b"cs_hunter2\r\n");
+    let secret = ClientSecret::from_file(&path).unwrap();
+    assert_eq!("cs_hunter2", secret.as_str());
+
+    let path = temp_file("lf", b"cs_hunter2\n\n");
+    let secret = ClientSecret::from_file(&path).unwrap();
+    assert_eq!("cs_hunter2\n", secret.as_str());
+
+    let path = temp_file("large", "cs_".repeat(1000).as_bytes());
+    let secret = ClientSecret::from_file(&path).unwrap();
+    assert_eq!(3000, secret.as_str().len());
+}
+
+#[cfg_attr(
+    miri,
+    ignore = "miri isolation prevents file system and environment access"
+)]
+#[test]
+fn invalid_value_is_not_included_in_error() {
+    let path = temp_file("invalid", b"hunter2");
+    let err = ClientSecret::from_file(&path).unwrap_err();
+
+    assert!(!err.to_string().contains("hunter2"), "{}", err);
+    assert!(!format!("{:?}", err).contains("hunter2"), "{:?}", err);
+    assert!(err.source().is_none());
+    assert_eq!(
+        format!(
+            "failed to load `ClientSecret` from file `{}`: invalid value",
+            path.display()
+        ),
+        err.to_string()
+    );
+    match err.into_kind() {
+        LoadErrorKind::Invalid(err) => assert_eq!("hunter2", err.into_inner().0),
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+}
+
+#[cfg_attr(
+    miri,
+    ignore = "miri isolation prevents file system and environment access"
+)]
+#[test]
+fn non_unicode_value_is_rejected() {
+    let path = temp_file("non_unicode", b"cs_\xff");
+    let err = ClientSecret::from_file(&path).unwrap_err();
+    assert!(matches!(err.kind(), LoadErrorKind::NotUnicode));
+}
+
+#[cfg_attr(
+    miri,
+    ignore = "miri isolation prevents file system and environment access"
+)]
+#[test]
+fn io_error_is_reported_as_source() {
+    let path = std::env::temp_dir().join("aliri_braid_load_does_not_exist");
+    let err = ApiKey::from_file(&path).unwrap_err();
+    assert!(matches!(err.kind(), LoadErrorKind::Io(_)));
+    assert!(err.source().is_some());
+    assert_eq!(&Origin::File(path), err.origin());
+}
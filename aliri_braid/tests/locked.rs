@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+
+use aliri_braid::{
+    braid,
+    locked::{self, LockedString},
+};
+
+#[braid(secret)]
+pub struct SigningKey(LockedString);
+
+#[braid(secret(zeroize))]
+pub struct ZeroizedSigningKey(LockedString);
+
+#[braid(secret, serde = "redact")]
+pub struct SerializedSigningKey(LockedString);
+
+#[cfg_attr(miri, ignore = "miri does not support locking memory")]
+#[test]
+fn round_trips_through_locked_storage() {
+    let key = SigningKey::from_static("hunter2");
+    assert_eq!("hunter2", key.as_str());
+    assert!(key.clone().take().is_locked() || locked::lock_failures() > 0);
+
+    let key = SigningKey::new(LockedString::from(String::from("hunter2")));
+    assert_eq!("hunter2", key.as_str());
+    assert_eq!("hunter2", String::from(key.take()));
+}
+
+#[test]
+fn empty_value_is_supported() {
+    let key = SigningKey::from_static("");
+    assert_eq!("", key.as_str());
+    assert!(!key.take().is_locked());
+}
+
+#[cfg_attr(miri, ignore = "miri does not support locking memory")]
+#[test]
+fn value_spanning_pages_is_supported() {
+    let value = "k".repeat(10_000);
+    let key = SigningKey::new(LockedString::from(value.as_str()));
+    assert_eq!(value, key.as_str());
+    assert_eq!(key, key.clone());
+}
+
+#[cfg_attr(miri, ignore = "miri does not support locking memory")]
+#[test]
+fn eq_and_hash_use_value() {
+    let key = SigningKey::from_static("hunter2");
+    let same = SigningKey::from_static("hunter2");
+    let other = SigningKey::from_static("hunter3");
+    assert_eq!(key, same);
+    assert_ne!(key, other);
+
+    let keys: HashSet<_> = vec![key, same, other].into_iter().collect();
+    assert_eq!(2, keys.len());
+}
+
+#[cfg_attr(miri, ignore = "miri does not support locking memory")]
+#[test]
+fn debug_does_not_reveal_value() {
+    let key = SigningKey::from_static("hunter2");
+    assert_eq!("[redacted SigningKey]", format!("{:?}", key));
+
+    let inner = format!("{:?}", key.take());
+    assert!(
+        inner.starts_with("LockedString { len: 7, locked: "),
+        "{}",
+        inner
+    );
+    assert!(!inner.contains("hunter2"), "{}", inner);
+}
+
+#[cfg_attr(miri, ignore = "miri does not support locking memory")]
+#[test]
+fn zeroize_wipes_in_place() {
+    use aliri_braid::zeroize::Zeroize;
+
+    let mut inner = LockedString::from("hunter2");
+    inner.zeroize();
+    assert_eq!("\0\0\0\0\0\0\0", inner.as_str());
+
+    let key = ZeroizedSigningKey::from_static("hunter2");
+    assert_eq!("hunter2", key.as_str());
+}
+
+#[cfg_attr(miri, ignore = "miri does not support locking memory")]
+#[test]
+fn serde_round_trips_through_locked_storage() {
+    let key: SerializedSigningKey = serde_json::from_str(r#""hunter2""#).unwrap();
+    assert_eq!("hunter2", key.as_str());
+    assert_eq!(
+        r#""[redacted SerializedSigningKey]""#,
+        serde_json::to_string(&key).unwrap()
+    );
+
+    let inner: LockedString = serde_json::from_str(r#""hunter\u0032""#).unwrap();
+    assert_eq!("hunter2", inner.as_str());
+    assert_eq!(r#""hunter2""#, serde_json::to_string(&inner).unwrap());
+}
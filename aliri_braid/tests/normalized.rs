@@ -50,7 +50,7 @@ impl aliri_braid::Validator for LowerString {
 }
 
 impl aliri_braid::Normalizer for LowerString {
-    fn normalize(s: &str) -> Result<Cow<str>, Self::Error> {
+    fn normalize(s: &str) -> Result<Cow<'_, str>, Self::Error> {
         if s.is_empty() {
             Err(InvalidString::EmptyString)
         } else if s.contains(|c: char| c.is_uppercase()) {
@@ -61,6 +61,45 @@ impl aliri_braid::Normalizer for LowerString {
     }
 }
 
+/// A [`String`] normalized to lowercase, which cannot contain whitespace
+#[braid(normalizer)]
+pub struct Handle;
+
+impl aliri_braid::Validator for Handle {
+    type Error = InvalidString;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        Self::validate_all(raw).map_err(|mut errors| errors.remove(0))
+    }
+
+    fn validate_all(raw: &str) -> Result<(), Vec<Self::Error>> {
+        let mut errors: Vec<_> = raw
+            .chars()
+            .filter(|c| c.is_uppercase() || c.is_whitespace())
+            .map(|_| InvalidString::InvalidCharacter)
+            .collect();
+        if raw.is_empty() {
+            errors.push(InvalidString::EmptyString);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl aliri_braid::Normalizer for Handle {
+    fn normalize(s: &str) -> Result<Cow<'_, str>, Self::Error> {
+        if s.is_empty() {
+            Err(InvalidString::EmptyString)
+        } else {
+            Ok(Cow::Owned(s.to_lowercase()))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -80,7 +119,7 @@ mod tests {
     #[test]
     fn owned_rejects_invalid() {
         let x = LowerString::new("".to_owned());
-        assert!(matches!(x, Err(_)));
+        assert!(x.is_err());
     }
 
     #[test]
@@ -109,6 +148,30 @@ mod tests {
         LowerStr::from_static("TestIng");
     }
 
+    #[test]
+    fn collecting_normalizes_before_validating() {
+        let x = Handle::try_new_collecting("Alice".to_owned()).unwrap();
+        assert_eq!(x.as_str(), "alice");
+    }
+
+    #[test]
+    fn collecting_reports_every_broken_rule() {
+        let errors = Handle::try_new_collecting("Alice In Chains".to_owned()).unwrap_err();
+        assert!(matches!(
+            errors[..],
+            [
+                InvalidString::InvalidCharacter,
+                InvalidString::InvalidCharacter
+            ]
+        ));
+    }
+
+    #[test]
+    fn collecting_reports_a_normalizer_error() {
+        let errors = Handle::try_new_collecting("".to_owned()).unwrap_err();
+        assert!(matches!(errors[..], [InvalidString::EmptyString]));
+    }
+
     fn needs_ref(_: &LowerStr) {}
     fn needs_owned(_: LowerString) {}
 
@@ -141,7 +204,7 @@ mod tests {
     #[test]
     fn ref_rejects_invalid() {
         let x = LowerStr::from_str("");
-        assert!(matches!(x, Err(_)));
+        assert!(x.is_err());
     }
 
     #[test]
@@ -153,13 +216,13 @@ mod tests {
     #[test]
     fn ref_norm_rejects_valid_non_normal() {
         let x = LowerStr::from_normalized_str("TestIng");
-        assert!(matches!(x, Err(_)));
+        assert!(x.is_err());
     }
 
     #[test]
     fn ref_norm_rejects_invalid() {
         let x = LowerStr::from_normalized_str("");
-        assert!(matches!(x, Err(_)));
+        assert!(x.is_err());
     }
 
     #[allow(dead_code)]
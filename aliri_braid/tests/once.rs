@@ -0,0 +1,173 @@
+use std::{
+    borrow::Borrow,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use aliri_braid::{
+    braid,
+    redaction::{self, Policy},
+    AlreadyTaken, Scrubber, SecretAccess, SecretAccessHook,
+};
+
+#[braid(secret(once))]
+pub struct ResetToken;
+
+#[braid(secret(once, zeroize, scrub), clone = "omit", serde = "redact")]
+pub struct BootstrapSecret;
+
+static TAKES: AtomicUsize = AtomicUsize::new(0);
+
+pub struct CountTakes;
+
+impl SecretAccessHook for CountTakes {
+    fn on_access(_: &'static str, access: SecretAccess) {
+        assert_eq!(SecretAccess::Take, access);
+        TAKES.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[braid(secret(once, audit = "CountTakes"))]
+pub struct AuditedToken;
+
+#[braid(secret(once), validator)]
+pub struct InviteCode {
+    code: String,
+}
+
+#[derive(Debug)]
+pub struct CodeTooLong;
+
+impl std::fmt::Display for CodeTooLong {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("code is too long")
+    }
+}
+
+impl std::error::Error for CodeTooLong {}
+
+impl From<std::convert::Infallible> for CodeTooLong {
+    fn from(x: std::convert::Infallible) -> Self {
+        match x {}
+    }
+}
+
+impl aliri_braid::Validator for InviteCode {
+    type Error = CodeTooLong;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.len() > 8 {
+            Err(CodeTooLong)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[braid(secret(once))]
+pub struct ClaimTicket {
+    taken: String,
+}
+
+static_assertions::assert_not_impl_any!(ResetToken: Clone, AsRef<str>, Borrow<str>, Into<String>);
+static_assertions::assert_not_impl_any!(ResetTokenRef: AsRef<str>, Borrow<str>, ToOwned);
+static_assertions::assert_not_impl_any!(ResetToken: From<&'static ResetTokenRef>);
+static_assertions::assert_not_impl_any!(Rc<ResetTokenRef>: From<&'static ResetTokenRef>);
+static_assertions::assert_not_impl_any!(Arc<ResetTokenRef>: From<&'static ResetTokenRef>);
+static_assertions::assert_not_impl_any!(BootstrapSecret: Clone);
+static_assertions::assert_not_impl_any!(InviteCode: Clone, From<&'static InviteCodeRef>);
+static_assertions::assert_not_impl_any!(InviteCodeRef: ToOwned);
+
+#[test]
+fn value_can_be_taken_once() {
+    let mut token = ResetToken::from_static("hunter2");
+    assert!(!token.is_taken());
+
+    assert_eq!("hunter2", token.take_once().unwrap());
+    assert!(token.is_taken());
+
+    let err = token.take_once().unwrap_err();
+    assert_eq!(AlreadyTaken::new("ResetToken"), err);
+    assert_eq!(
+        "the value of `ResetToken` has already been taken",
+        err.to_string()
+    );
+}
+
+#[test]
+fn empty_value_can_be_taken_once() {
+    let mut token = ResetToken::from_static("");
+    assert!(!token.is_taken());
+    assert_eq!("", token.take_once().unwrap());
+    assert!(token.is_taken());
+    assert!(token.take_once().is_err());
+}
+
+#[test]
+fn validated_value_can_be_taken_once() {
+    let mut code: InviteCode = "abc123".parse().unwrap();
+    assert!("too long for it".parse::<InviteCode>().is_err());
+
+    assert_eq!("abc123", code.take_once().unwrap());
+    assert!(code.is_taken());
+    assert!(code.take_once().is_err());
+
+    let mut code = InviteCode::from_static("");
+    assert_eq!("", code.take_once().unwrap());
+    assert!(code.take_once().is_err());
+}
+
+#[test]
+fn field_named_taken_is_not_confused_with_flag() {
+    let mut ticket = ClaimTicket::new("t-42".to_owned());
+    assert!(!ticket.is_taken());
+    assert_eq!("t-42", ticket.take_once().unwrap());
+    assert!(ticket.is_taken());
+}
+
+#[test]
+fn value_is_never_revealed_by_formatting() {
+    let token = ResetToken::from_static("hunter2");
+    assert_eq!("[redacted ResetToken]", format!("{token:#}"));
+    assert_eq!("[redacted ResetToken]", format!("{token:#?}"));
+
+    redaction::with_policy(Policy::Reveal, || {
+        assert_eq!("[redacted ResetToken]", token.to_string());
+        assert_eq!("[redacted ResetTokenRef]", format!("{:?}", &*token));
+    })
+    .unwrap();
+}
+
+#[test]
+fn taking_value_unregisters_it_from_scrubber() {
+    let mut secret = BootstrapSecret::from_static("once-upon-a-time");
+    let message = "bootstrapping with once-upon-a-time";
+    assert_eq!(
+        "bootstrapping with [redacted BootstrapSecret]",
+        Scrubber::global().scrub(message),
+    );
+
+    let value = secret.take_once().unwrap();
+    assert_eq!("once-upon-a-time", value);
+    assert_eq!(message, Scrubber::global().scrub(message));
+    assert_eq!(
+        "\"[redacted BootstrapSecret]\"",
+        serde_json::to_string(&secret).unwrap(),
+    );
+}
+
+#[test]
+fn taking_value_is_audited() {
+    let mut token = AuditedToken::from_static("hunter2");
+    assert_eq!("[redacted AuditedToken]", token.to_string());
+    assert_eq!(0, TAKES.load(Ordering::Relaxed));
+
+    token.take_once().unwrap();
+    assert_eq!(1, TAKES.load(Ordering::Relaxed));
+
+    token.take_once().unwrap_err();
+    assert_eq!(1, TAKES.load(Ordering::Relaxed));
+}
@@ -0,0 +1,77 @@
+use aliri_braid::{
+    braid, braid_ref,
+    redaction::{self, Policy},
+};
+
+#[braid(secret)]
+pub struct ApiKey;
+
+#[braid(secret(last = 4, none))]
+pub struct CardNumber;
+
+#[braid_ref(secret(length_only))]
+pub struct TokenStr;
+
+#[braid(secret = "strict")]
+pub struct SigningKey;
+
+#[test]
+fn reveal_policy_formats_as_if_not_secret() {
+    let key = ApiKey::from_static("hunter2");
+    let card = CardNumber::from_static("4242424242424242");
+    let token = TokenStr::from_str("hunter2");
+
+    redaction::with_policy(Policy::Reveal, || {
+        assert_eq!(Policy::Reveal, redaction::policy());
+        assert_eq!("hunter2", key.to_string());
+        assert_eq!("\"hunter2\"", format!("{:?}", key));
+        assert_eq!("\"hunter2\"", format!("{:?}", &*key));
+        assert_eq!("4242424242424242", card.to_string());
+        assert_eq!("hunter2", token.to_string());
+    })
+    .unwrap();
+}
+
+#[test]
+fn reveal_policy_does_not_reveal_strict_secrets() {
+    let key = SigningKey::from_static("hunter2");
+
+    redaction::with_policy(Policy::Reveal, || {
+        assert_eq!("[redacted SigningKey]", key.to_string());
+        assert_eq!("[redacted SigningKey]", format!("{:?}", key));
+        assert_eq!("[redacted SigningKeyRef]", format!("{:?}", &*key));
+    })
+    .unwrap();
+}
+
+#[test]
+fn fingerprint_policy_overrides_strategy() {
+    let card = CardNumber::from_static("4242424242424242");
+
+    redaction::with_policy(Policy::Fingerprint, || {
+        let debug = format!("{:?}", card);
+        assert!(debug.starts_with("[redacted CardNumber"), "{}", debug);
+        assert!(!debug.contains("4242"), "{}", debug);
+        assert_eq!(debug, format!("{:#?}", card));
+    })
+    .unwrap();
+}
+
+#[test]
+fn scoped_policy_is_restored() {
+    let key = ApiKey::from_static("hunter2");
+
+    let result = std::panic::catch_unwind(|| {
+        redaction::with_policy(Policy::Reveal, || panic!("oops")).unwrap();
+    });
+    assert!(result.is_err());
+
+    redaction::with_policy(Policy::Redact, || {
+        redaction::with_policy(Policy::Reveal, || {
+            assert_eq!("hunter2", key.to_string());
+        })
+        .unwrap();
+        assert_eq!("[redacted ApiKey]", key.to_string());
+    })
+    .unwrap();
+}
@@ -0,0 +1,53 @@
+use aliri_braid::{
+    braid,
+    redaction::{self, Policy},
+};
+
+#[braid(secret)]
+pub struct ApiKey;
+
+// The process-wide policy affects all threads and the lock cannot be undone, so the
+// sequence of changes is checked within a single test.
+#[test]
+fn global_policy_can_be_locked() {
+    redaction::with_policy(Policy::Redact, || {
+        redaction::set_policy(Policy::Reveal).unwrap();
+        assert_eq!(Policy::Redact, redaction::policy());
+    })
+    .unwrap();
+
+    let key = ApiKey::from_static("hunter2");
+    let handle = std::thread::spawn(move || {
+        assert_eq!(Policy::Reveal, redaction::policy());
+        key.to_string()
+    });
+    assert_eq!("hunter2", handle.join().unwrap());
+
+    assert!(!redaction::is_policy_locked());
+    redaction::with_policy(Policy::Reveal, || {
+        let key = ApiKey::from_static("hunter2");
+        assert_eq!("hunter2", key.to_string());
+
+        redaction::lock_policy();
+        assert_eq!(Policy::Redact, redaction::policy());
+        assert_eq!("[redacted ApiKey]", key.to_string());
+    })
+    .unwrap();
+    assert!(redaction::is_policy_locked());
+
+    let policy = std::thread::spawn(redaction::policy).join().unwrap();
+    assert_eq!(Policy::Redact, policy);
+
+    assert_eq!(
+        Err(redaction::PolicyLocked),
+        redaction::set_policy(Policy::Reveal)
+    );
+    assert_eq!(
+        Err(redaction::PolicyLocked),
+        redaction::with_policy(Policy::Reveal, || ())
+    );
+    redaction::set_policy(Policy::Fingerprint).unwrap();
+    let policy = std::thread::spawn(redaction::policy).join().unwrap();
+    assert_eq!(Policy::Fingerprint, policy);
+    redaction::set_policy(Policy::Redact).unwrap();
+}
@@ -0,0 +1,181 @@
+use std::error::Error;
+
+use aliri_braid::{braid, braid_ref, Validator};
+
+#[braid(
+    serde,
+    validate(non_empty, max_len = 64, charset = "a-z0-9_-", prefix = "usr_")
+)]
+pub struct UserId;
+
+#[braid(validate(min_len = 3, max_len = 8))]
+pub struct Nickname;
+
+#[braid(validate(charset = "-+0-9"))]
+pub struct SignedDigits;
+
+#[braid(secret, validate(min_len = 12))]
+pub struct Passphrase;
+
+#[braid_ref(validate(non_empty, prefix = "#"))]
+pub struct Channel;
+
+static_assertions::assert_impl_all!(InvalidUserId: Error, Clone, PartialEq, From<std::convert::Infallible>);
+
+#[test]
+fn valid_values_are_accepted() {
+    let id = UserId::new("usr_alice-01".to_owned()).unwrap();
+    assert_eq!("usr_alice-01", id.as_str());
+    assert!(UserIdRef::from_str("usr_").is_ok());
+    assert!(Nickname::from_static("bob").as_str().len() == 3);
+    assert!(SignedDigits::new("-12+3".to_owned()).is_ok());
+    assert!(Channel::from_str("#general").is_ok());
+}
+
+#[test]
+fn empty_values_are_rejected() {
+    assert_eq!(Err(InvalidUserId::Empty), UserId::validate(""));
+    assert_eq!(
+        "value must not be empty",
+        UserId::new(String::new()).unwrap_err().to_string()
+    );
+    assert_eq!(Err(InvalidChannel::Empty), Channel::validate(""));
+}
+
+#[test]
+fn lengths_are_checked_in_bytes() {
+    assert_eq!(
+        Err(InvalidNickname::TooShort { len: 2 }),
+        Nickname::validate("ab")
+    );
+    assert_eq!(
+        Err(InvalidNickname::TooLong { len: 9 }),
+        Nickname::validate("abcdefghi")
+    );
+    assert_eq!(
+        Err(InvalidNickname::TooShort { len: 2 }),
+        Nickname::validate("é")
+    );
+    assert_eq!(
+        "value must be at most 8 bytes long, but was 9 bytes",
+        NicknameRef::from_str("abcdefghi").unwrap_err().to_string()
+    );
+
+    let long = format!("usr_{}", "a".repeat(61));
+    assert_eq!(
+        Err(InvalidUserId::TooLong { len: 65 }),
+        UserId::validate(&long)
+    );
+}
+
+#[test]
+fn prefix_is_required() {
+    assert_eq!(Err(InvalidUserId::MissingPrefix), UserId::validate("alice"));
+    assert_eq!(
+        "value must start with `usr_`",
+        InvalidUserId::MissingPrefix.to_string()
+    );
+    assert_eq!(
+        Err(InvalidChannel::MissingPrefix),
+        Channel::validate("general")
+    );
+}
+
+#[test]
+fn characters_outside_charset_are_rejected() {
+    assert_eq!(
+        Err(InvalidUserId::InvalidChar { ch: 'A', offset: 4 }),
+        UserId::validate("usr_Alice")
+    );
+    assert_eq!(
+        Err(InvalidUserId::InvalidChar {
+            ch: '!',
+            offset: 10
+        }),
+        UserId::validate("usr_alice_!")
+    );
+    assert_eq!(
+        "invalid character 'Z' at offset 5, expected one of `a-z0-9_-`",
+        UserId::new("usr_aZ".to_owned()).unwrap_err().to_string()
+    );
+    assert_eq!(
+        Err(InvalidSignedDigits::InvalidChar { ch: 'x', offset: 1 }),
+        SignedDigits::validate("1x")
+    );
+}
+
+#[test]
+fn rules_are_checked_when_deserializing() {
+    assert!(serde_json::from_str::<UserId>("\"usr_bob\"").is_ok());
+    let err = serde_json::from_str::<UserId>("\"bob\"").unwrap_err();
+    assert!(err.to_string().starts_with("value must start with `usr_`"));
+}
+
+#[test]
+fn errors_of_secret_braids_are_redacted() {
+    let err = Passphrase::new("hunter2".to_owned()).unwrap_err();
+    assert_eq!("invalid value for `Passphrase`", err.to_string());
+    assert_eq!(&InvalidPassphrase::TooShort { len: 7 }, err.get_ref());
+}
+
+const GENERAL: &Channel = Channel::from_static("#general");
+const ADMIN: &UserIdRef = UserIdRef::from_static("usr_admin");
+
+#[braid(validate(charset = "a-zà-ÿ"))]
+pub struct Accented;
+
+#[test]
+fn from_static_is_checked_at_compile_time() {
+    assert_eq!("#general", GENERAL.as_str());
+    assert_eq!("usr_admin", ADMIN.as_str());
+}
+
+#[test]
+fn const_validation_agrees_with_validator() {
+    for value in [
+        "",
+        "usr_",
+        "usr_alice",
+        "alice",
+        "usr_Alice",
+        "usr_é",
+        "usr_a!",
+    ] {
+        assert_eq!(
+            UserId::validate(value).is_ok(),
+            UserId::validate_const(value).is_ok(),
+            "{}",
+            value
+        );
+    }
+
+    for value in ["", "ab", "abcd", "abcdefghi", "ééé", "😀😀"] {
+        assert_eq!(
+            Nickname::validate(value).is_ok(),
+            Nickname::validate_const(value).is_ok(),
+            "{}",
+            value
+        );
+    }
+
+    for value in ["abc", "crème", "naïve", "Ünïcode", "€uro", "😀"] {
+        assert_eq!(
+            Accented::validate(value).is_ok(),
+            Accented::validate_const(value).is_ok(),
+            "{}",
+            value
+        );
+    }
+
+    assert_eq!(
+        Err("value must only contain characters in `a-z0-9_-`"),
+        UserId::validate_const("usr_A")
+    );
+}
+
+#[test]
+#[should_panic(expected = "value must start with `usr_`")]
+fn from_static_panics_at_runtime_outside_const() {
+    let value: &'static str = Box::leak(String::from("admin").into_boxed_str());
+    UserIdRef::from_static(value);
+}
@@ -0,0 +1,210 @@
+use std::borrow::Cow;
+
+use aliri_braid::{
+    braid,
+    redaction::{self, Policy},
+    Scrubber, SecretAccess, SecretAccessHook,
+};
+
+#[braid(secret(scrub))]
+pub struct ApiKey;
+
+#[braid(secret(scrub, zeroize, last = 4), serde = "expose")]
+pub struct CardNumber;
+
+#[braid(secret(scrub, zeroize))]
+pub struct SessionKey;
+
+#[braid(secret)]
+pub struct UnscrubbedKey;
+
+thread_local! {
+    static ACCESSES: std::cell::RefCell<Vec<SecretAccess>> = Default::default();
+}
+
+pub struct RecordAccess;
+
+impl SecretAccessHook for RecordAccess {
+    fn on_access(_: &'static str, access: SecretAccess) {
+        ACCESSES.with(|a| a.borrow_mut().push(access));
+    }
+}
+
+#[braid(secret(scrub, fingerprint, audit = "RecordAccess"))]
+pub struct AuditedKey;
+
+// Each test uses distinct secret values, as they share the global scrubber
+fn global_scrub(text: &str) -> String {
+    Scrubber::global().scrub(text).into_owned()
+}
+
+#[test]
+fn live_values_are_scrubbed() {
+    let key = ApiKey::from_static("live-api-key");
+    assert_eq!(
+        "request to /?key=[redacted ApiKey] failed",
+        global_scrub("request to /?key=live-api-key failed")
+    );
+
+    drop(key);
+    assert_eq!(
+        "request to /?key=live-api-key failed",
+        global_scrub("request to /?key=live-api-key failed")
+    );
+}
+
+#[test]
+fn registrations_are_counted() {
+    let key = ApiKey::new("counted-api-key".to_owned());
+    let copy = key.clone();
+    let owned = key.to_owned();
+    drop(key);
+    drop(copy);
+    assert_eq!("[redacted ApiKey]", global_scrub("counted-api-key"));
+
+    let raw = owned.take();
+    assert_eq!("counted-api-key", raw);
+    assert_eq!("counted-api-key", global_scrub("counted-api-key"));
+}
+
+#[test]
+fn boxed_refs_are_not_registered() {
+    let boxed = ApiKey::from_static("boxed-api-key").into_boxed_ref();
+    assert_eq!("boxed-api-key", global_scrub("boxed-api-key"));
+
+    let key = boxed.into_owned();
+    assert_eq!("[redacted ApiKey]", global_scrub("boxed-api-key"));
+    drop(key);
+    assert_eq!("boxed-api-key", global_scrub("boxed-api-key"));
+}
+
+#[test]
+fn replacement_uses_redaction_strategy() -> Result<(), serde_json::Error> {
+    let card: CardNumber = serde_json::from_str("\"4111111111111111\"")?;
+    assert_eq!(
+        "card ************1111 declined",
+        global_scrub("card 4111111111111111 declined")
+    );
+
+    drop(card);
+    assert_eq!(
+        "card 4111111111111111 declined",
+        global_scrub("card 4111111111111111 declined")
+    );
+    Ok(())
+}
+
+#[test]
+fn zeroized_values_are_unregistered() {
+    let key = SessionKey::from_static("zeroized-session-key");
+    let copy = key.clone();
+    assert_eq!(
+        "[redacted SessionKey]",
+        global_scrub("zeroized-session-key")
+    );
+
+    drop(key);
+    assert_eq!(
+        "[redacted SessionKey]",
+        global_scrub("zeroized-session-key")
+    );
+    drop(copy);
+    assert_eq!("zeroized-session-key", global_scrub("zeroized-session-key"));
+
+    let raw = SessionKey::from_static("taken-session-key").take();
+    assert_eq!("taken-session-key", global_scrub(&raw));
+
+    let boxed = SessionKey::from_static("boxed-session-key").into_boxed_ref();
+    assert_eq!("boxed-session-key", global_scrub(boxed.as_str()));
+}
+
+#[test]
+fn replacement_ignores_runtime_policy() {
+    let _ = redaction::set_fingerprint_key([0x5a; 16]);
+
+    let revealed =
+        redaction::with_policy(Policy::Reveal, || AuditedKey::from_static("revealed-key")).unwrap();
+    assert_eq!("[redacted AuditedKey]", global_scrub("revealed-key"));
+
+    let fingerprinted = redaction::with_policy(Policy::Fingerprint, || {
+        AuditedKey::from_static("fingerprinted-key")
+    })
+    .unwrap();
+    assert_eq!("[redacted AuditedKey]", global_scrub("fingerprinted-key"));
+
+    assert!(ACCESSES.with(|a| a.take()).is_empty());
+    drop((revealed, fingerprinted));
+}
+
+#[test]
+fn secrets_can_be_registered_explicitly() {
+    let key = UnscrubbedKey::from_static("explicit-key");
+    assert_eq!("explicit-key", global_scrub("explicit-key"));
+
+    let scrubber = Scrubber::new();
+    scrubber.register(&key);
+    assert_eq!("[redacted UnscrubbedKey]", scrubber.scrub("explicit-key"));
+    assert_eq!("explicit-key", global_scrub("explicit-key"));
+}
+
+#[test]
+fn explicit_registration_ignores_runtime_policy() {
+    let _ = redaction::set_fingerprint_key([0x5a; 16]);
+    let key = UnscrubbedKey::from_static("explicit-revealed-key");
+    let audited = AuditedKey::from_static("explicit-audited-key");
+    ACCESSES.with(|a| a.take());
+
+    let scrubber = Scrubber::new();
+    redaction::with_policy(Policy::Reveal, || scrubber.register(&key)).unwrap();
+    redaction::with_policy(Policy::Fingerprint, || scrubber.register(&*audited)).unwrap();
+    assert_eq!(
+        "[redacted UnscrubbedKey] [redacted AuditedKeyRef]",
+        scrubber.scrub("explicit-revealed-key explicit-audited-key")
+    );
+
+    // The value is read in order to register it, but is never formatted
+    assert_eq!(vec![SecretAccess::AsStr], ACCESSES.with(|a| a.take()));
+}
+
+#[test]
+fn scrubber_replaces_longest_match() {
+    let scrubber = Scrubber::new();
+    assert!(scrubber.is_empty());
+    assert!(matches!(scrubber.scrub("abc"), Cow::Borrowed("abc")));
+
+    scrubber.insert("secret", "[short]");
+    scrubber.insert("secret-longer", "[long]");
+    scrubber.insert("", "[empty]");
+    assert_eq!(2, scrubber.len());
+
+    assert_eq!(
+        "[long] and [short], [short]",
+        scrubber.scrub("secret-longer and secret, secret")
+    );
+    assert!(matches!(
+        scrubber.scrub("nothing here"),
+        Cow::Borrowed("nothing here")
+    ));
+
+    scrubber.remove("secret-longer");
+    assert_eq!("[short]-longer", scrubber.scrub("secret-longer"));
+
+    scrubber.clear();
+    assert!(scrubber.is_empty());
+    assert_eq!("secret", scrubber.scrub("secret"));
+}
+
+#[test]
+fn scrubber_counts_insertions() {
+    let scrubber = Scrubber::new();
+    scrubber.insert("token", "[first]");
+    scrubber.insert("token", "[second]");
+    assert_eq!("[first]", scrubber.scrub("token"));
+
+    scrubber.remove("token");
+    assert_eq!("[first]", scrubber.scrub("token"));
+
+    scrubber.remove("token");
+    scrubber.remove("token");
+    assert_eq!("token", scrubber.scrub("token"));
+}
@@ -0,0 +1,243 @@
+use std::{
+    error::Error,
+    fmt,
+    sync::{Mutex, MutexGuard},
+};
+
+use aliri_braid::{
+    braid,
+    seal::{self, SealErrorKind, Sealed, Sealer, TestSealer},
+    SecretAccess, SecretAccessHook, Validator,
+};
+
+#[braid(secret, serde = "sealed")]
+pub struct RefreshToken;
+
+#[braid(secret(zeroize), serde = "sealed", validator)]
+pub struct DatabasePassword;
+
+#[derive(Debug)]
+pub struct InvalidDatabasePassword;
+
+impl fmt::Display for InvalidDatabasePassword {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("database passwords must be at least 8 bytes")
+    }
+}
+
+impl Error for InvalidDatabasePassword {}
+
+aliri_braid::from_infallible!(InvalidDatabasePassword);
+
+impl Validator for DatabasePassword {
+    type Error = InvalidDatabasePassword;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.len() < 8 {
+            Err(InvalidDatabasePassword)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+thread_local! {
+    static ACCESSES: std::cell::RefCell<Vec<(&'static str, SecretAccess)>> = Default::default();
+}
+
+pub struct RecordAccess;
+
+impl SecretAccessHook for RecordAccess {
+    fn on_access(type_name: &'static str, access: SecretAccess) {
+        ACCESSES.with(|a| a.borrow_mut().push((type_name, access)));
+    }
+}
+
+#[braid(secret(audit = "RecordAccess"), serde = "sealed")]
+pub struct AuditedToken;
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Row {
+    user: String,
+    refresh_token: RefreshToken,
+}
+
+/// The sealer is process-wide, so tests that install one must not run concurrently
+fn install(sealer: TestSealer) -> MutexGuard<'static, ()> {
+    static LOCK: Mutex<()> = Mutex::new(());
+    let guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    seal::set_sealer(sealer);
+    guard
+}
+
+#[test]
+fn sealed_values_round_trip() {
+    let _guard = install(TestSealer::new("k1", b"first key"));
+
+    let row = Row {
+        user: "alice".into(),
+        refresh_token: RefreshToken::from_static("hunter2"),
+    };
+    let json = serde_json::to_string(&row).unwrap();
+    assert!(json.contains("\"refresh_token\":\"k1."));
+    assert!(!json.contains("hunter2"));
+
+    let row: Row = serde_json::from_str(&json).unwrap();
+    assert_eq!(RefreshToken::from_static("hunter2"), row.refresh_token);
+}
+
+#[test]
+fn borrowed_values_are_sealed_as_owned() {
+    let _guard = install(TestSealer::new("k1", b"first key"));
+
+    let token = RefreshToken::from_static("hunter2");
+    let json = serde_json::to_string(&*token).unwrap();
+    assert!(!json.contains("hunter2"));
+
+    let unsealed: RefreshToken = serde_json::from_str(&json).unwrap();
+    assert_eq!(token, unsealed);
+
+    let boxed: Box<RefreshTokenRef> = serde_json::from_str(&json).unwrap();
+    assert_eq!(&*token, &*boxed);
+}
+
+#[test]
+fn sealing_is_audited() {
+    let _guard = install(TestSealer::new("k1", b"first key"));
+
+    let token = AuditedToken::from_static("hunter2");
+    let owned = serde_json::to_string(&token).unwrap();
+    let borrowed = serde_json::to_string(&*token).unwrap();
+    let _: AuditedToken = serde_json::from_str(&owned).unwrap();
+    assert!(!borrowed.contains("hunter2"));
+
+    assert_eq!(
+        vec![
+            ("AuditedToken", SecretAccess::Serialize),
+            ("AuditedTokenRef", SecretAccess::Serialize),
+        ],
+        ACCESSES.with(|a| a.take())
+    );
+}
+
+#[test]
+fn rotated_keys_unseal_old_values() {
+    let _guard = install(TestSealer::new("k1", b"first key"));
+    let old = serde_json::to_string(&RefreshToken::from_static("hunter2")).unwrap();
+
+    seal::set_sealer(TestSealer::new("k1", b"first key").with_key("k2", b"second key"));
+    let new = serde_json::to_string(&RefreshToken::from_static("hunter2")).unwrap();
+    assert!(new.starts_with("\"k2."));
+
+    let old: RefreshToken = serde_json::from_str(&old).unwrap();
+    let new: RefreshToken = serde_json::from_str(&new).unwrap();
+    assert_eq!(old, new);
+}
+
+#[test]
+fn retired_keys_are_reported() {
+    let _guard = install(TestSealer::new("k1", b"first key"));
+    let json = serde_json::to_string(&RefreshToken::from_static("hunter2")).unwrap();
+
+    seal::set_sealer(TestSealer::new("k2", b"second key"));
+    let err = serde_json::from_str::<RefreshToken>(&json).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("failed to unseal `RefreshToken`: unknown key `k1`"));
+}
+
+#[test]
+fn malformed_values_are_rejected() {
+    let _guard = install(TestSealer::new("k1", b"first key"));
+
+    for json in [r#""hunter2""#, r#"".aGVsbG8""#, r#""k1.a""#, r#""k1.!!!!""#] {
+        let err = serde_json::from_str::<RefreshToken>(json).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("failed to unseal `RefreshToken`: malformed sealed value"),
+            "{}: {}",
+            json,
+            err
+        );
+    }
+}
+
+#[test]
+fn values_sealed_for_another_type_are_rejected() {
+    let _guard = install(TestSealer::new("k1", b"first key"));
+    let json = serde_json::to_string(&DatabasePassword::from_static("correct horse")).unwrap();
+
+    let err = serde_json::from_str::<RefreshToken>(&json).unwrap_err();
+    assert!(err.to_string().contains("checksum mismatch"));
+}
+
+#[test]
+fn unsealed_values_are_validated() {
+    let _guard = install(TestSealer::new("k1", b"first key"));
+
+    let sealed = seal::seal("DatabasePassword", "short").unwrap();
+    let json = serde_json::to_string(&sealed).unwrap();
+    let err = serde_json::from_str::<DatabasePassword>(&json).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("failed to unseal `DatabasePassword`: invalid value"));
+    assert!(!err.to_string().contains("short"));
+
+    let sealed = seal::seal("DatabasePassword", "correct horse").unwrap();
+    let json = serde_json::to_string(&sealed).unwrap();
+    let password: DatabasePassword = serde_json::from_str(&json).unwrap();
+    assert_eq!(DatabasePassword::from_static("correct horse"), password);
+}
+
+#[test]
+fn sealed_format_is_key_id_and_base64url() {
+    for (ciphertext, encoded) in [
+        (&b""[..], "k."),
+        (b"f", "k.Zg"),
+        (b"fo", "k.Zm8"),
+        (b"foo", "k.Zm9v"),
+        (b"\xfb\xff", "k.-_8"),
+    ] {
+        let sealed = Sealed::new("k", ciphertext.to_vec());
+        assert_eq!(encoded, sealed.to_string());
+        assert_eq!(sealed, encoded.parse().unwrap());
+    }
+
+    let sealed = "key.v2.Zm9v".parse::<Sealed>().unwrap();
+    assert_eq!("key.v2", sealed.key_id());
+    assert_eq!(b"foo", sealed.ciphertext());
+}
+
+struct FailingSealer;
+
+impl Sealer for FailingSealer {
+    fn seal(&self, _: &str, _: &[u8]) -> Result<Sealed, SealErrorKind> {
+        Err(SealErrorKind::Failed("key service unavailable".into()))
+    }
+
+    fn unseal(&self, _: &str, _: &Sealed) -> Result<Vec<u8>, SealErrorKind> {
+        Err(SealErrorKind::Failed("key service unavailable".into()))
+    }
+}
+
+#[test]
+fn sealer_failures_are_reported_without_the_value() {
+    let _guard = install(TestSealer::new("k1", b"first key"));
+    seal::set_sealer(FailingSealer);
+
+    let err = seal::seal("RefreshToken", "hunter2").unwrap_err();
+    assert_eq!("RefreshToken", err.type_name());
+    assert!(matches!(err.kind(), SealErrorKind::Failed(_)));
+    assert_eq!(
+        "failed to seal `RefreshToken`: key service unavailable",
+        err.to_string()
+    );
+    assert!(err.source().is_some());
+    assert!(!format!("{err:?}").contains("hunter2"));
+
+    let err = serde_json::to_string(&RefreshToken::from_static("hunter2")).unwrap_err();
+    assert_eq!(
+        "failed to seal `RefreshToken`: key service unavailable",
+        err.to_string()
+    );
+}
@@ -0,0 +1,658 @@
+use std::{
+    borrow::Cow,
+    collections::{BTreeSet, HashSet},
+    convert::Infallible,
+    fmt,
+};
+
+use aliri_braid::{braid, SecretAccess, SecretAccessHook};
+
+#[braid(secret(zeroize))]
+pub struct ZeroizedSecret;
+
+#[braid(secret(zeroize))]
+pub struct NamedZeroizedSecret {
+    value: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct EmptySecret;
+
+impl fmt::Display for EmptySecret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("secret cannot be empty")
+    }
+}
+
+impl From<Infallible> for EmptySecret {
+    #[inline(always)]
+    fn from(x: Infallible) -> Self {
+        match x {}
+    }
+}
+
+impl std::error::Error for EmptySecret {}
+
+#[braid(secret(zeroize), validator)]
+pub struct ValidatedZeroizedSecret;
+
+impl aliri_braid::Validator for ValidatedZeroizedSecret {
+    type Error = EmptySecret;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.is_empty() {
+            Err(EmptySecret)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[braid(secret(zeroize), normalizer)]
+pub struct NormalizedZeroizedSecret;
+
+impl aliri_braid::Validator for NormalizedZeroizedSecret {
+    type Error = EmptySecret;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.trim().is_empty() || raw.trim() != raw {
+            Err(EmptySecret)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl aliri_braid::Normalizer for NormalizedZeroizedSecret {
+    fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+        let trimmed = raw.trim();
+        if trimmed.is_empty() {
+            Err(EmptySecret)
+        } else if trimmed.len() != raw.len() {
+            Ok(Cow::Owned(trimmed.to_owned()))
+        } else {
+            Ok(Cow::Borrowed(raw))
+        }
+    }
+}
+
+#[braid(
+    secret(zeroize),
+    normalizer = "aliri_braid::validators::TrimWhitespace"
+)]
+pub struct TrimmedZeroizedSecret;
+
+#[braid(secret)]
+pub struct PlainSecret;
+
+#[braid(secret, ord = "impl")]
+pub struct OrderedSecret;
+
+#[braid(ord = "owned", secret)]
+pub struct OwnedOrderedSecret;
+
+impl PartialOrd for OwnedOrderedSecretRef {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OwnedOrderedSecretRef {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.len().cmp(&other.0.len())
+    }
+}
+
+#[braid(secret = "strict")]
+pub struct StrictSecret;
+
+#[braid(secret(strict, zeroize), validator = "ValidatedZeroizedSecret")]
+pub struct StrictZeroizedSecret;
+
+#[aliri_braid::braid_ref(secret = "strict")]
+pub struct StrictSecretStr;
+
+#[braid(secret(last = 4))]
+pub struct CardNumber;
+
+#[braid(secret(message = "<token>"))]
+pub struct MessageSecret;
+
+#[braid(secret(length_only, none))]
+pub struct LengthOnlySecret;
+
+#[braid(secret(message = "***", debug(length_only), display(none)))]
+pub struct SplitRedactionSecret;
+
+#[aliri_braid::braid_ref(secret(last = 2, none))]
+pub struct MaskedSecretStr;
+
+#[braid(secret(last = 4), serde = "redact")]
+pub struct RedactedSerdeSecret;
+
+#[braid(secret, serde = "error")]
+pub struct RefusedSerdeSecret;
+
+#[braid(secret, serde = "expose")]
+pub struct ExposedSerdeSecret;
+
+#[aliri_braid::braid_ref(secret, serde = "redact")]
+pub struct RedactedSerdeSecretStr;
+
+#[derive(serde::Serialize)]
+struct Credentials<'a> {
+    user: &'a str,
+    key: &'a RedactedSerdeSecretRef,
+}
+
+thread_local! {
+    static ACCESSES: std::cell::RefCell<Vec<(&'static str, SecretAccess)>> = Default::default();
+}
+
+fn take_accesses() -> Vec<(&'static str, SecretAccess)> {
+    ACCESSES.with(|a| a.take())
+}
+
+pub struct RecordAccess;
+
+impl SecretAccessHook for RecordAccess {
+    fn on_access(type_name: &'static str, access: SecretAccess) {
+        ACCESSES.with(|a| a.borrow_mut().push((type_name, access)));
+    }
+}
+
+#[braid(secret(audit = "RecordAccess"), serde = "expose")]
+pub struct AuditedSecret;
+
+#[braid(secret(audit = "RecordAccess", zeroize, strict))]
+pub struct StrictAuditedSecret;
+
+#[braid(secret(audit = "RecordAccess"), clone = "explicit")]
+pub struct DuplicableSecret;
+
+#[braid(
+    secret(zeroize),
+    clone = "explicit",
+    validator = "ValidatedZeroizedSecret"
+)]
+pub struct DuplicableZeroizedSecret {
+    value: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct MissingPrefix;
+
+impl fmt::Display for MissingPrefix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("missing `sk_` prefix")
+    }
+}
+
+impl std::error::Error for MissingPrefix {}
+
+/// An error that, like many in the wild, echoes the rejected input
+#[derive(Debug, PartialEq, Eq)]
+pub struct InvalidSecretKey {
+    value: String,
+    reason: MissingPrefix,
+}
+
+impl fmt::Display for InvalidSecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid secret key `{}`", self.value)
+    }
+}
+
+impl std::error::Error for InvalidSecretKey {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.reason)
+    }
+}
+
+aliri_braid::from_infallible!(InvalidSecretKey);
+
+#[braid(secret, validator, serde = "redact")]
+pub struct SecretKey;
+
+impl aliri_braid::Validator for SecretKey {
+    type Error = InvalidSecretKey;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.starts_with("sk_") {
+            Ok(())
+        } else {
+            Err(InvalidSecretKey {
+                value: raw.to_owned(),
+                reason: MissingPrefix,
+            })
+        }
+    }
+}
+
+#[aliri_braid::braid_ref(secret, validator = "SecretKey")]
+pub struct SecretKeyStr;
+
+#[test]
+fn zeroized_secrets_implement_drop() {
+    assert!(std::mem::needs_drop::<ZeroizedSecret>());
+    assert!(std::mem::needs_drop::<ZeroizedSecretRef>());
+    assert!(std::mem::needs_drop::<NamedZeroizedSecretRef>());
+    assert!(std::mem::needs_drop::<ValidatedZeroizedSecretRef>());
+    assert!(std::mem::needs_drop::<NormalizedZeroizedSecretRef>());
+    assert!(!std::mem::needs_drop::<PlainSecretRef>());
+}
+
+#[test]
+#[allow(unsafe_code)]
+fn dropping_boxed_ref_wipes_value() {
+    let boxed = ZeroizedSecret::from_static("hunter2").into_boxed_ref();
+    let raw = Box::into_raw(boxed);
+
+    // SAFETY: `raw` came from a `Box` and is only dropped in place here, after which the
+    // allocation is still live until reconstituted as a `Box<str>`, which has no drop glue.
+    unsafe {
+        std::ptr::drop_in_place(raw);
+        assert_eq!("\0\0\0\0\0\0\0", (*raw).as_str());
+        drop(Box::from_raw(raw as *mut str));
+    }
+}
+
+#[test]
+fn take_yields_inner_value() {
+    let secret = ZeroizedSecret::from_static("hunter2");
+    assert_eq!("hunter2", secret.take());
+
+    let secret = NamedZeroizedSecret::from_static("hunter2");
+    assert_eq!("hunter2", String::from(secret));
+}
+
+#[test]
+fn boxed_ref_round_trips() {
+    let secret = ZeroizedSecret::new(String::with_capacity(64) + "hunter2");
+    let boxed: Box<ZeroizedSecretRef> = secret.into();
+    assert_eq!("hunter2", boxed.as_str());
+    assert_eq!(ZeroizedSecret::from_static("hunter2"), boxed.into_owned());
+}
+
+#[test]
+fn validated_secret_rejects_invalid() {
+    assert_eq!(
+        EmptySecret,
+        ValidatedZeroizedSecret::new(String::new())
+            .unwrap_err()
+            .into_inner()
+    );
+    assert_eq!(
+        "hunter2",
+        ValidatedZeroizedSecret::new("hunter2".to_owned())
+            .unwrap()
+            .as_str()
+    );
+}
+
+#[test]
+fn normalized_secret_normalizes() {
+    assert_eq!(
+        EmptySecret,
+        NormalizedZeroizedSecret::new("   ".to_owned())
+            .unwrap_err()
+            .into_inner()
+    );
+    assert_eq!(
+        "hunter2",
+        NormalizedZeroizedSecret::new(" hunter2 ".to_owned())
+            .unwrap()
+            .as_str()
+    );
+    assert_eq!(
+        "hunter2",
+        NormalizedZeroizedSecret::new("hunter2".to_owned())
+            .unwrap()
+            .as_str()
+    );
+}
+
+#[test]
+fn zeroized_secret_applies_trimming_normalizer() {
+    assert_eq!(
+        "hunter2",
+        TrimmedZeroizedSecret::new("  hunter2 ".to_owned())
+            .unwrap()
+            .as_str()
+    );
+    assert_eq!(
+        "hunter2",
+        TrimmedZeroizedSecret::new("hunter2".to_owned())
+            .unwrap()
+            .as_str()
+    );
+    assert_eq!(
+        "hunter2",
+        TrimmedZeroizedSecretRef::from_str("\thunter2\n")
+            .unwrap()
+            .as_str()
+    );
+}
+
+#[test]
+fn zeroized_secret_is_redacted() {
+    let secret = ZeroizedSecret::from_static("hunter2");
+    assert_eq!("[redacted ZeroizedSecret]", format!("{:?}", secret));
+    assert_eq!("[redacted ZeroizedSecretRef]", format!("{}", &*secret));
+}
+
+#[test]
+fn secrets_omit_ord_by_default() {
+    static_assertions::assert_not_impl_any!(PlainSecret: PartialOrd, Ord);
+    static_assertions::assert_not_impl_any!(PlainSecretRef: PartialOrd, Ord);
+    static_assertions::assert_not_impl_any!(ZeroizedSecret: PartialOrd, Ord);
+    static_assertions::assert_not_impl_any!(ZeroizedSecretRef: PartialOrd, Ord);
+}
+
+#[test]
+fn secrets_can_opt_back_in_to_ord() {
+    static_assertions::assert_impl_all!(OrderedSecret: PartialOrd, Ord);
+    static_assertions::assert_impl_all!(OrderedSecretRef: PartialOrd, Ord);
+    static_assertions::assert_impl_all!(OwnedOrderedSecret: PartialOrd, Ord);
+
+    let set: BTreeSet<_> = ["b", "a", "c"]
+        .iter()
+        .map(|&s| OrderedSecret::from(s))
+        .collect();
+    let values: Vec<_> = set.iter().map(|s| s.as_str()).collect();
+    assert_eq!(vec!["a", "b", "c"], values);
+}
+
+#[test]
+fn secret_equality_across_forms() {
+    let owned = PlainSecret::from_static("hunter2");
+    let other = PlainSecret::from_static("hunter3");
+    let borrowed = PlainSecretRef::from_static("hunter2");
+    let boxed = owned.clone().into_boxed_ref();
+
+    assert_eq!(owned, owned.clone());
+    assert_ne!(owned, other);
+    assert_ne!(owned, PlainSecret::from_static("hunter"));
+    assert_eq!(owned, borrowed);
+    assert_eq!(owned, *borrowed);
+    assert_eq!(borrowed, owned);
+    assert_eq!(*borrowed, owned);
+    assert_ne!(borrowed, other);
+    assert_ne!(other, *borrowed);
+    assert_eq!(&*boxed, borrowed);
+    assert_eq!(owned, &*boxed);
+    assert_eq!(boxed, borrowed.to_owned().into_boxed_ref());
+}
+
+#[test]
+fn secrets_can_be_hash_keys() {
+    let mut set = HashSet::new();
+    assert!(set.insert(PlainSecret::from_static("hunter2")));
+    assert!(!set.insert(PlainSecret::from_static("hunter2")));
+    assert!(set.contains(PlainSecretRef::from_static("hunter2")));
+    assert!(!set.contains(PlainSecretRef::from_static("hunter3")));
+}
+
+#[test]
+fn strict_secrets_omit_implicit_str_access() {
+    static_assertions::assert_not_impl_any!(StrictSecret: AsRef<str>, std::borrow::Borrow<str>, Into<String>);
+    static_assertions::assert_not_impl_any!(StrictSecretRef: AsRef<str>, std::borrow::Borrow<str>);
+    static_assertions::assert_not_impl_any!(StrictZeroizedSecret: AsRef<str>, std::borrow::Borrow<str>, Into<String>);
+    static_assertions::assert_not_impl_any!(StrictZeroizedSecretRef: AsRef<str>, std::borrow::Borrow<str>);
+    static_assertions::assert_not_impl_any!(StrictSecretStr: AsRef<str>, std::borrow::Borrow<str>);
+}
+
+#[test]
+fn strict_secrets_expose_explicitly() {
+    let secret = StrictSecret::from_static("hunter2");
+    assert_eq!("hunter2", secret.expose_secret());
+    assert_eq!(7, secret.with_exposed(str::len));
+    assert_eq!("[redacted StrictSecret]", format!("{:?}", secret));
+    assert_eq!("[redacted StrictSecret]", format!("{:#?}", secret));
+    assert_eq!("[redacted StrictSecret]", format!("{:#}", secret));
+    assert_eq!("[redacted StrictSecretRef]", format!("{:#}", &*secret));
+
+    let secret = StrictZeroizedSecret::new("hunter2".to_owned()).unwrap();
+    assert_eq!("hunter2", secret.expose_secret());
+    assert!(StrictZeroizedSecret::new(String::new()).is_err());
+
+    let secret = StrictSecretStr::from_str("hunter2");
+    assert_eq!("hunter2", secret.expose_secret());
+    assert!(secret.with_exposed(|s| s.starts_with("hunter")));
+}
+
+#[test]
+fn masked_secrets_show_trailing_characters() {
+    let card = CardNumber::from_static("4242424242424242");
+    assert_eq!("************4242", format!("{:?}", card));
+    assert_eq!("************4242", format!("{}", card));
+    assert_eq!("************4242", format!("{}", &*card));
+    assert_eq!("4242424242424242", format!("{:#}", card));
+
+    let short = CardNumber::from_static("42");
+    assert_eq!("**", format!("{}", short));
+
+    let unicode = CardNumber::from_static("ü🏗ü🏗ü");
+    assert_eq!("*🏗ü🏗ü", format!("{}", unicode));
+}
+
+#[test]
+fn message_secrets_use_fixed_message() {
+    let secret = MessageSecret::from_static("hunter2");
+    assert_eq!("<token>", format!("{:?}", secret));
+    assert_eq!("<token>", format!("{}", &*secret));
+    assert_eq!("hunter2", format!("{:#}", secret));
+}
+
+#[test]
+fn length_only_secrets_cannot_be_revealed() {
+    let secret = LengthOnlySecret::from_static("hunter2");
+    assert_eq!("[redacted, 7 chars]", format!("{:?}", secret));
+    assert_eq!("[redacted, 7 chars]", format!("{:#?}", secret));
+    assert_eq!("[redacted, 7 chars]", format!("{:#}", &*secret));
+}
+
+#[test]
+fn debug_and_display_redact_separately() {
+    let secret = SplitRedactionSecret::from_static("hunter2");
+    assert_eq!("[redacted, 7 chars]", format!("{:?}", secret));
+    assert_eq!("\"hunter2\"", format!("{:#?}", secret));
+    assert_eq!("***", format!("{}", secret));
+    assert_eq!("***", format!("{:#}", secret));
+
+    let secret = MaskedSecretStr::from_str("hunter2");
+    assert_eq!("*****r2", format!("{:?}", secret));
+    assert_eq!("*****r2", format!("{:#}", secret));
+}
+
+#[test]
+fn redacted_serde_secrets_serialize_redaction() -> Result<(), Box<dyn std::error::Error>> {
+    let secret = RedactedSerdeSecret::from_static("hunter2");
+    assert_eq!("\"***ter2\"", serde_json::to_string(&secret)?);
+    assert_eq!("\"***ter2\"", serde_json::to_string(&*secret)?);
+
+    let credentials = Credentials {
+        user: "root",
+        key: &secret,
+    };
+    assert_eq!(
+        r#"{"user":"root","key":"***ter2"}"#,
+        serde_json::to_string(&credentials)?
+    );
+
+    let secret: &RedactedSerdeSecretStr = serde_json::from_str("\"hunter2\"")?;
+    assert_eq!(
+        "\"[redacted RedactedSerdeSecretStr]\"",
+        serde_json::to_string(secret)?
+    );
+
+    let deserialized: RedactedSerdeSecret = serde_json::from_str("\"hunter2\"")?;
+    assert_eq!(RedactedSerdeSecret::from_static("hunter2"), deserialized);
+    Ok(())
+}
+
+#[test]
+fn refused_serde_secrets_fail_to_serialize() -> Result<(), Box<dyn std::error::Error>> {
+    let secret = RefusedSerdeSecret::from_static("hunter2");
+    let err = serde_json::to_string(&secret).unwrap_err();
+    assert!(!err.to_string().contains("hunter2"));
+    assert!(serde_json::to_string(&*secret).is_err());
+
+    let deserialized: RefusedSerdeSecret = serde_json::from_str("\"hunter2\"")?;
+    assert_eq!(secret, deserialized);
+    Ok(())
+}
+
+#[test]
+fn exposed_serde_secrets_serialize_value() -> Result<(), Box<dyn std::error::Error>> {
+    let secret = ExposedSerdeSecret::from_static("hunter2");
+    assert_eq!("\"hunter2\"", serde_json::to_string(&secret)?);
+    assert_eq!("\"hunter2\"", serde_json::to_string(&*secret)?);
+
+    let deserialized: ExposedSerdeSecret = serde_json::from_str("\"hunter2\"")?;
+    assert_eq!(secret, deserialized);
+    Ok(())
+}
+
+#[test]
+fn audited_secrets_report_raw_access() -> Result<(), Box<dyn std::error::Error>> {
+    use std::{borrow::Borrow, collections::HashSet};
+
+    let secret = AuditedSecret::from_static("hunter2");
+    let copy = secret.clone();
+    let mut set = HashSet::new();
+    set.insert(copy.clone());
+    assert!(set.contains(AuditedSecretRef::from_static("hunter2")));
+    assert_eq!(secret, copy);
+    assert_eq!("[redacted AuditedSecret]", format!("{}", secret));
+    assert_eq!("[redacted AuditedSecretRef]", format!("{:?}", &*secret));
+    let boxed = copy.into_boxed_ref();
+    assert_eq!(Vec::<(&str, SecretAccess)>::new(), take_accesses());
+
+    assert_eq!("hunter2", secret.as_str());
+    assert_eq!("hunter2", AsRef::<str>::as_ref(&secret));
+    assert_eq!("hunter2", Borrow::<str>::borrow(&*boxed));
+    assert_eq!(
+        vec![
+            ("AuditedSecretRef", SecretAccess::AsStr),
+            ("AuditedSecretRef", SecretAccess::AsStr),
+            ("AuditedSecretRef", SecretAccess::AsStr),
+        ],
+        take_accesses()
+    );
+
+    assert_eq!("hunter2", format!("{:#}", secret));
+    assert_eq!("\"hunter2\"", format!("{:#?}", &*secret));
+    aliri_braid::redaction::with_policy(aliri_braid::redaction::Policy::Reveal, || {
+        assert_eq!("hunter2", format!("{}", secret));
+    })?;
+    assert_eq!(
+        vec![
+            ("AuditedSecret", SecretAccess::Format),
+            ("AuditedSecretRef", SecretAccess::Format),
+            ("AuditedSecret", SecretAccess::Format),
+        ],
+        take_accesses()
+    );
+
+    assert_eq!("\"hunter2\"", serde_json::to_string(&secret)?);
+    assert_eq!("\"hunter2\"", serde_json::to_string(&*boxed)?);
+    let _: AuditedSecret = serde_json::from_str("\"hunter2\"")?;
+    assert_eq!(
+        vec![
+            ("AuditedSecret", SecretAccess::Serialize),
+            ("AuditedSecretRef", SecretAccess::Serialize),
+        ],
+        take_accesses()
+    );
+
+    assert_eq!("hunter2", secret.clone().take());
+    assert_eq!("hunter2", String::from(secret));
+    assert_eq!(
+        vec![
+            ("AuditedSecret", SecretAccess::Take),
+            ("AuditedSecret", SecretAccess::Take),
+        ],
+        take_accesses()
+    );
+    Ok(())
+}
+
+#[test]
+fn audited_strict_secrets_report_exposure() {
+    let secret = StrictAuditedSecret::from_static("hunter2");
+    assert_eq!(secret, StrictAuditedSecret::from_static("hunter2"));
+    assert_eq!(Vec::<(&str, SecretAccess)>::new(), take_accesses());
+
+    assert_eq!("hunter2", secret.expose_secret());
+    assert_eq!(7, secret.with_exposed(str::len));
+    assert_eq!(
+        vec![
+            ("StrictAuditedSecretRef", SecretAccess::AsStr),
+            ("StrictAuditedSecretRef", SecretAccess::AsStr),
+        ],
+        take_accesses()
+    );
+}
+
+#[test]
+fn validation_errors_do_not_reveal_secret() {
+    use std::{convert::TryFrom, error::Error};
+
+    fn assert_redacted<E: Error>(err: E) {
+        assert_eq!("invalid value for `SecretKeyRef`", err.to_string());
+        assert!(!format!("{:?}", err).contains("hunter2"), "{:?}", err);
+        assert_eq!("missing `sk_` prefix", err.source().unwrap().to_string());
+    }
+
+    assert_redacted("hunter2".parse::<SecretKey>().unwrap_err());
+    assert_redacted(SecretKey::try_from("hunter2").unwrap_err());
+    assert_redacted(<&SecretKeyRef>::try_from("hunter2").unwrap_err());
+
+    let err = SecretKey::try_from("hunter2".to_owned()).unwrap_err();
+    assert_eq!("invalid value for `SecretKey`", err.to_string());
+    assert_eq!("SecretKey", err.type_name());
+    assert_eq!("hunter2", err.get_ref().value);
+
+    let err = SecretKeyStr::from_str("hunter2").unwrap_err();
+    assert_eq!("invalid value for `SecretKeyStr`", err.to_string());
+    assert_eq!(MissingPrefix, err.into_inner().reason);
+
+    assert_eq!("sk_hunter2", SecretKey::from_static("sk_hunter2").as_str());
+}
+
+#[test]
+fn validation_panics_do_not_reveal_secret() {
+    let panic = std::panic::catch_unwind(|| SecretKey::from_static("hunter2")).unwrap_err();
+    let message = panic.downcast_ref::<String>().unwrap();
+    assert!(message.starts_with("invalid SecretKeyRef: "), "{}", message);
+    assert!(!message.contains("hunter2"), "{}", message);
+}
+
+#[test]
+fn deserialization_errors_do_not_reveal_secret() {
+    let err = serde_json::from_str::<SecretKey>("\"hunter2\"").unwrap_err();
+    assert_eq!("invalid value for `SecretKey`", err.to_string());
+
+    let err = serde_json::from_str::<&SecretKeyRef>("\"hunter2\"").unwrap_err();
+    assert_eq!("invalid value for `SecretKeyRef`", err.to_string());
+}
+
+#[test]
+fn explicit_clone_generates_duplicate() {
+    static_assertions::assert_not_impl_any!(DuplicableSecret: Clone);
+    static_assertions::assert_not_impl_any!(DuplicableZeroizedSecret: Clone);
+
+    let secret = DuplicableSecret::from_static("hunter2");
+    let copy = secret.duplicate();
+    assert_eq!(secret, copy);
+    assert_eq!(
+        vec![("DuplicableSecret", SecretAccess::Duplicate)],
+        take_accesses()
+    );
+
+    let secret = DuplicableZeroizedSecret::from_static("hunter2");
+    let copy = secret.duplicate();
+    drop(secret);
+    assert_eq!("hunter2", copy.as_str());
+}
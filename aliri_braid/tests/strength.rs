@@ -0,0 +1,128 @@
+use aliri_braid::{
+    braid,
+    validators::{estimate_bits, CharClasses, Strength, StrengthError, StrengthPolicy},
+};
+
+#[braid(secret, validator = "aliri_braid::validators::Strength")]
+pub struct Password;
+
+pub struct Pin;
+
+impl StrengthPolicy for Pin {
+    const MIN_BITS: u32 = 13;
+    const BANNED_WORDS: &'static [&'static str] = &["1234", "0000"];
+    const REQUIRED_CLASSES: CharClasses = CharClasses::DIGIT;
+}
+
+#[braid(secret, validator = "Strength<Pin>")]
+pub struct PinCode;
+
+pub struct Mixed;
+
+impl StrengthPolicy for Mixed {
+    const MIN_BITS: u32 = 0;
+    const REQUIRED_CLASSES: CharClasses = CharClasses::LOWERCASE.union(CharClasses::UPPERCASE);
+    const MIN_CLASSES: u32 = 3;
+}
+
+#[braid(secret, validator = "Strength<Mixed>")]
+pub struct MixedSecret;
+
+#[test]
+fn recommended_policy_accepts_strong_values() {
+    assert!(Password::new("correct horse battery staple".to_owned()).is_ok());
+    assert!(Password::new("x7#Qp!2vLm9@".to_owned()).is_ok());
+}
+
+#[test]
+fn recommended_policy_rejects_common_passwords() {
+    let err = Password::new("MySuperLongPassWord!2024".to_owned()).unwrap_err();
+    assert_eq!(&StrengthError::BannedWord, err.get_ref());
+
+    let err = Password::new("Tr0ub4dor".to_owned()).unwrap_err();
+    assert!(matches!(
+        err.get_ref(),
+        StrengthError::TooWeak { min_bits: 60, .. }
+    ));
+}
+
+#[test]
+fn required_classes_are_enforced() {
+    let err = PinCode::new("abcdefgh".to_owned()).unwrap_err();
+    assert_eq!(
+        &StrengthError::MissingClasses(CharClasses::DIGIT),
+        err.get_ref()
+    );
+    assert_eq!("value must contain digits", err.get_ref().to_string());
+
+    assert!(PinCode::new("8302".to_owned()).is_ok());
+    assert!(PinCode::new("01234".to_owned()).is_err());
+    assert!(PinCode::new("2222".to_owned()).is_err());
+
+    let err = MixedSecret::new("abc123".to_owned()).unwrap_err();
+    assert_eq!(
+        "value must contain uppercase letters",
+        err.get_ref().to_string()
+    );
+}
+
+#[test]
+fn minimum_number_of_classes_is_enforced() {
+    let err = MixedSecret::new("abcXYZ".to_owned()).unwrap_err();
+    assert_eq!(
+        &StrengthError::TooFewClasses {
+            found: 2,
+            required: 3
+        },
+        err.get_ref()
+    );
+    assert!(MixedSecret::new("abcXYZ!".to_owned()).is_ok());
+}
+
+#[test]
+fn errors_never_include_the_value() {
+    for value in ["hunter2", "password1", "HUNTER", "passw0rd-hunter"] {
+        let err = Password::new(value.to_owned()).unwrap_err();
+        for message in [
+            err.to_string(),
+            format!("{:?}", err),
+            err.get_ref().to_string(),
+            format!("{:?}", err.get_ref()),
+        ] {
+            assert!(!message.contains(value), "{}", message);
+            assert!(!message.to_lowercase().contains("hunter"), "{}", message);
+        }
+    }
+}
+
+#[test]
+fn repeats_and_runs_contribute_one_bit() {
+    assert_eq!(4, estimate_bits("a"));
+    assert_eq!(4 + 5, estimate_bits("aaaaaa"));
+    assert_eq!(4 + 5, estimate_bits("abcdef"));
+    assert_eq!(3 + 5, estimate_bits("987654"));
+    assert_eq!(18, estimate_bits("acac"));
+    assert_eq!(0, estimate_bits(""));
+}
+
+#[test]
+fn estimate_grows_with_classes() {
+    assert!(estimate_bits("zqxjvk") < estimate_bits("zQxJvK"));
+    assert!(estimate_bits("zQxJvK") < estimate_bits("zQ7J!K"));
+    assert!(estimate_bits("zQ7J!K") < estimate_bits("zQ7J!K\u{e9}"));
+}
+
+#[test]
+fn char_classes_are_displayed_by_name() {
+    let classes = CharClasses::of("aZ9 é");
+    assert_eq!(5, classes.len());
+    assert_eq!(
+        "lowercase letters, uppercase letters, digits, symbols, other characters",
+        classes.to_string()
+    );
+    assert_eq!(
+        "CharClasses(digits, symbols)",
+        format!("{:?}", CharClasses::DIGIT | CharClasses::SYMBOL)
+    );
+    assert_eq!("no characters", CharClasses::NONE.to_string());
+}
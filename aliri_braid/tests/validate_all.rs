@@ -0,0 +1,126 @@
+use aliri_braid::{
+    braid, braid_ref,
+    validators::{All, AllError, AsciiPrintable, Map, MaxLen, NonEmpty},
+    ValidationError, Validator,
+};
+
+#[braid(validate(min_len = 6, max_len = 12, prefix = "usr_", charset = "a-z"))]
+pub struct UserId;
+
+#[braid(validator = "All<(NonEmpty, AsciiPrintable, MaxLen<4>)>")]
+pub struct Tag;
+
+#[braid(
+    secret(zeroize),
+    validator = "Map<All<(AsciiPrintable, MaxLen<4>)>, Rejected>"
+)]
+pub struct Pin;
+
+#[braid_ref(validate(non_empty, charset = "0-9"), no_std)]
+pub struct Digits;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Rejected(usize);
+
+impl std::fmt::Display for Rejected {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "rejected at {}", self.0)
+    }
+}
+
+impl From<AllError<ValidationError, ValidationError>> for Rejected {
+    fn from(error: AllError<ValidationError, ValidationError>) -> Self {
+        match error {
+            AllError::First(e) | AllError::Second(e) => Self(e.offset()),
+            _ => unreachable!(),
+        }
+    }
+}
+
+aliri_braid::from_infallible!(Rejected);
+
+#[braid(validator)]
+pub struct Single;
+
+impl Validator for Single {
+    type Error = ValidationError;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        NonEmpty::validate(raw)
+    }
+}
+
+#[test]
+fn declarative_rules_report_every_failure() {
+    assert!(UserId::try_new_collecting("usr_alice".to_owned()).is_ok());
+
+    let errors = UserId::try_new_collecting("Bob".to_owned()).unwrap_err();
+    assert_eq!(
+        vec![
+            InvalidUserId::TooShort { len: 3 },
+            InvalidUserId::MissingPrefix,
+            InvalidUserId::InvalidChar { ch: 'B', offset: 0 },
+        ],
+        errors
+    );
+
+    let errors = UserId::try_new_collecting("usr_A_B".to_owned()).unwrap_err();
+    assert_eq!(
+        vec![
+            InvalidUserId::InvalidChar { ch: 'A', offset: 4 },
+            InvalidUserId::InvalidChar { ch: '_', offset: 5 },
+            InvalidUserId::InvalidChar { ch: 'B', offset: 6 },
+        ],
+        errors
+    );
+}
+
+#[test]
+fn all_reports_each_validator() {
+    let errors = Tag::try_new_collecting("ab\tcd".to_owned()).unwrap_err();
+    let rules: Vec<_> = errors
+        .iter()
+        .map(|e| match e {
+            AllError::Second(e) | AllError::Third(e) => (e.rule(), e.type_name()),
+            other => panic!("unexpected error: {:?}", other),
+        })
+        .collect();
+    assert_eq!(
+        vec![("ascii_printable", Some("Tag")), ("max_len", Some("Tag"))],
+        rules
+    );
+
+    assert_eq!(
+        Tag::new("ab\tcd".to_owned()).unwrap_err(),
+        errors.into_iter().next().unwrap()
+    );
+}
+
+#[test]
+fn map_converts_every_error() {
+    let errors = Pin::try_new_collecting("12\n45".to_owned()).unwrap_err();
+    let errors: Vec<_> = errors.into_iter().map(|e| e.into_inner()).collect();
+    assert_eq!(vec![Rejected(2), Rejected(4)], errors);
+
+    assert!(Pin::try_new_collecting("1234".to_owned()).is_ok());
+}
+
+#[test]
+fn secret_errors_are_redacted() {
+    let errors = Pin::try_new_collecting("12\n45".to_owned()).unwrap_err();
+    assert!(errors
+        .iter()
+        .all(|e| !format!("{} {:?}", e, e).contains("12")));
+}
+
+#[test]
+fn default_reports_the_single_error() {
+    let errors = Single::try_new_collecting(String::new()).unwrap_err();
+    assert_eq!(1, errors.len());
+    assert_eq!("non_empty", errors[0].rule());
+
+    assert_eq!(
+        Digits::validate("").unwrap_err(),
+        Digits::validate_all("").unwrap_err()[0]
+    );
+}
@@ -0,0 +1,116 @@
+use std::error::Error;
+
+use aliri_braid::{
+    braid,
+    validators::{All, AllError, AsciiPrintable, MaxLen, NoControlChars, NonEmpty},
+    ValidationError,
+};
+
+#[braid(validator = "All<(NonEmpty, NoControlChars, MaxLen<8>)>")]
+pub struct Label;
+
+#[braid(secret, validator = "AsciiPrintable")]
+pub struct ApiKey;
+
+#[braid(validate(non_empty, min_len = 2, max_len = 8, prefix = "#", charset = "a-z"))]
+pub struct Channel;
+
+#[braid(secret, validate(charset = "a-z0-9"))]
+pub struct Token;
+
+static_assertions::assert_impl_all!(
+    ValidationError: Error, Clone, PartialEq, From<std::convert::Infallible>, Send, Sync
+);
+
+fn validation_error(
+    err: AllError<ValidationError, ValidationError, ValidationError>,
+) -> ValidationError {
+    match err {
+        AllError::First(e) | AllError::Second(e) | AllError::Third(e) => e,
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn errors_carry_the_braid_name_and_span() {
+    let err = validation_error(Label::new("ab\ncd".to_owned()).unwrap_err());
+    assert_eq!(Some("Label"), err.type_name());
+    assert_eq!("no_control_chars", err.rule());
+    assert_eq!(2, err.offset());
+    assert_eq!(2..3, err.span());
+    assert!(!err.is_redacted());
+    assert_eq!(
+        "invalid `Label`: value must not contain control characters",
+        err.to_string()
+    );
+
+    let err = validation_error(LabelRef::from_str("").unwrap_err());
+    assert_eq!(Some("LabelRef"), err.type_name());
+}
+
+#[test]
+fn errors_render_a_caret_under_the_span() {
+    let input = "ab\ncd";
+    let err = validation_error(Label::new(input.to_owned()).unwrap_err());
+    assert_eq!(
+        "invalid `Label`: value must not contain control characters\n  | ab\\ncd\n  |   ^^",
+        err.render(input).to_string()
+    );
+
+    let input = "cafés, etc";
+    let err = validation_error(Label::new(input.to_owned()).unwrap_err());
+    assert_eq!(8..11, err.span());
+    assert_eq!(
+        "invalid `Label`: value is longer than the maximum length\n  | cafés, etc\n  |        ^^^",
+        err.render(input).to_string()
+    );
+
+    let err = ValidationError::new("min_len", "value is too short", 3, 0);
+    assert_eq!(
+        "value is too short\n  | abc\n  |    ^",
+        err.render("abc").to_string()
+    );
+}
+
+#[test]
+fn errors_of_secret_braids_are_redacted() {
+    let err = ApiKey::new("key\twith tab".to_owned()).unwrap_err();
+    let inner = err.get_ref();
+    assert_eq!(Some("ApiKey"), inner.type_name());
+    assert!(inner.is_redacted());
+    assert_eq!(3..4, inner.span());
+
+    let rendered = inner.render("key\twith tab").to_string();
+    assert_eq!(
+        "invalid `ApiKey`: value must only contain printable ASCII characters\n  | [redacted]",
+        rendered
+    );
+    assert!(!format!("{:?}", inner.render("key\twith tab")).contains("key"));
+}
+
+#[test]
+fn declarative_errors_convert() {
+    let cases = [
+        ("", "non_empty", 0..0),
+        ("#", "min_len", 1..1),
+        ("#abcdefghij", "max_len", 8..11),
+        ("abc", "prefix", 0..0),
+        ("#abçd", "charset", 3..5),
+    ];
+    for (input, rule, span) in cases {
+        let err = ValidationError::from(Channel::new(input.to_owned()).unwrap_err());
+        assert_eq!((rule, span), (err.rule(), err.span()), "{}", input);
+        assert_eq!(Some("Channel"), err.type_name());
+        assert!(!err.is_redacted());
+    }
+
+    let err = ValidationError::from(Channel::new("#A".to_owned()).unwrap_err());
+    assert_eq!(
+        "invalid `Channel`: value must only contain characters in `a-z`\n  | #A\n  |  ^",
+        err.render("#A").to_string()
+    );
+
+    let err = ValidationError::from(Token::new("abc!".to_owned()).unwrap_err().into_inner());
+    assert!(err.is_redacted());
+    assert!(!err.render("abc!").to_string().contains("abc"));
+}
@@ -0,0 +1,19 @@
+//! An example of constructing a strongly-typed wrapper around a string value
+//! validated by declarative rules.
+//!
+//! The `validate(...)` rules generate both the [`Validator`][aliri_braid::Validator]
+//! implementation and an error type describing which rule was broken.
+
+use aliri_braid::{braid, braid_ref};
+
+/// A user identifier, such as `usr_alice`
+#[braid(
+    serde,
+    validate(non_empty, max_len = 64, charset = "a-z0-9_-", prefix = "usr_"),
+    ref_doc = "A borrowed reference to a [`UserId`]"
+)]
+pub struct UserId;
+
+/// A lowercase tag, usable without the standard library
+#[braid_ref(validate(non_empty, max_len = 16, charset = "a-z"), no_std)]
+pub struct Tag;
@@ -6,6 +6,7 @@
 //!   * A wrapper around a string with [small-string optimizations][sso_wrapper]
 //!   * A wrapper around a string [backed by `Bytes`][bytes]
 //! * [Validated][validated]
+//!   * Validated by [declarative rules][declarative]
 //! * [Normalized][normalized]
 //!
 //! In addition, the [`minimal`] module demonstrates the minimal string
@@ -13,6 +14,7 @@
 #![deny(unsafe_code)]
 
 pub mod bytes;
+pub mod declarative;
 pub mod minimal;
 pub mod normalized;
 pub mod ref_only;
@@ -11,6 +11,8 @@ pub struct RefCodeGen<'a> {
     pub ident: syn::Ident,
     pub field: Field,
     pub check_mode: &'a CheckMode,
+    pub const_validator: bool,
+    pub context_validator: bool,
     pub owned_ty: Option<&'a syn::Ident>,
     pub std_lib: &'a StdLib,
     pub impls: &'a Impls,
@@ -21,18 +23,68 @@ impl<'a> RefCodeGen<'a> {
         let ty = &self.ty;
         let field_name = &self.field.name;
         let inherent = self.check_inherent();
+        let audit = self.impls.secret.audit(&self.ident, "AsStr");
+        let constness = audit.is_none().then(|| quote! { const });
 
-        quote! {
-            #[automatically_derived]
-            impl #ty {
-                #inherent
+        let accessors = if self.impls.secret.is_once() {
+            // The value of a `once` secret can only be read by taking it from the owned form
+            quote! {}
+        } else if self.impls.secret.is_strict() {
+            quote! {
+                /// Exposes the underlying secret value as a string slice
+                ///
+                /// Every use of the secret value goes through this method or
+                /// [`with_exposed`](Self::with_exposed), making each access
+                /// easy to find and review.
+                #[inline]
+                pub #constness fn expose_secret(&self) -> &str {
+                    #audit
+                    &self.#field_name
+                }
 
+                /// Exposes the underlying secret value to the provided closure
+                ///
+                /// The exposed string slice cannot escape the closure.
+                #[inline]
+                pub fn with_exposed<R, F: FnOnce(&str) -> R>(&self, f: F) -> R {
+                    #audit
+                    f(&self.#field_name)
+                }
+            }
+        } else {
+            quote! {
                 /// Provides access to the underlying value as a string slice.
                 #[inline]
-                pub const fn as_str(&self) -> &str {
+                pub #constness fn as_str(&self) -> &str {
+                    #audit
                     &self.#field_name
                 }
             }
+        };
+
+        let fingerprint = self.impls.secret.is_fingerprinted().then(|| {
+            let core = self.std_lib.core();
+            quote! {
+                /// Computes the keyed fingerprint of the secret value
+                ///
+                /// Returns `None` if no key has been set with
+                /// [`set_fingerprint_key()`](::aliri_braid::redaction::set_fingerprint_key).
+                #[inline]
+                pub fn fingerprint(&self) -> ::#core::option::Option<::aliri_braid::redaction::Fingerprint> {
+                    ::aliri_braid::redaction::Fingerprint::of(&self.#field_name)
+                }
+            }
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl #ty {
+                #inherent
+
+                #accessors
+
+                #fingerprint
+            }
         }
     }
 
@@ -186,14 +238,79 @@ impl<'a> RefCodeGen<'a> {
             }
         });
 
+        let from_static = if self.const_validator {
+            quote! {
+                #[allow(unsafe_code)]
+                #[inline]
+                #[doc = #static_doc_comment]
+                #[doc = ""]
+                #[doc = "The value is checked with `validate_const()`, so when called in a `const` context, an invalid value fails to compile."]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "This function will panic if the provided raw string is not valid."]
+                #[track_caller]
+                pub const fn from_static(raw: &'static str) -> &'static Self {
+                    match <#validator>::validate_const(raw) {
+                        ::#core::result::Result::Ok(()) => {
+                            #unchecked_safety_comment
+                            unsafe { Self::from_str_unchecked(raw) }
+                        }
+                        ::#core::result::Result::Err(reason) => ::#core::panic!("{}", reason),
+                    }
+                }
+            }
+        } else {
+            quote! {
+                #[inline]
+                #[doc = #static_doc_comment]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "This function will panic if the provided raw string is not valid."]
+                #[track_caller]
+                pub fn from_static(raw: &'static str) -> &'static Self {
+                    Self::from_str(raw).expect(concat!("invalid ", stringify!(#ty)))
+                }
+            }
+        };
+
+        let from_str_with = self.context_validator.then(|| {
+            let doc_comment = format!(
+                "Transparently reinterprets the string slice as a strongly-typed {} if it \
+                 conforms to [`{}`], including the rules that depend on the given context",
+                self.ident,
+                validator.to_token_stream(),
+            );
+            let context_validator = crate::as_context_validator(validator);
+            let validator = crate::as_validator(validator);
+            let error = self.impls.secret.error_type(&validator);
+            let map_err = self.impls.secret.map_error(&validator, &self.ident, core);
+
+            quote! {
+                #[inline]
+                #[doc = #doc_comment]
+                pub fn from_str_with<'a>(
+                    context: &#context_validator::Context,
+                    raw: &'a str,
+                ) -> ::#core::result::Result<&'a Self, #error> {
+                    let value = Self::from_str(raw)?;
+                    #context_validator::validate_with(context, raw)#map_err?;
+                    ::#core::result::Result::Ok(value)
+                }
+            }
+        });
+
         let validator = crate::as_validator(validator);
+        let error = self.impls.secret.error_type(&validator);
+        let map_err = self.impls.secret.map_error(&validator, &self.ident, core);
 
         quote! {
             #[allow(unsafe_code)]
             #[inline]
             #[doc = #doc_comment]
-            pub fn from_str(raw: &str) -> ::#core::result::Result<&Self, #validator::Error> {
-                #validator::validate(raw)?;
+            pub fn from_str(raw: &str) -> ::#core::result::Result<&Self, #error> {
+                #validator::validate(raw)#map_err?;
                 #unchecked_safety_comment
                 ::#core::result::Result::Ok(unsafe { Self::from_str_unchecked(raw) })
             }
@@ -206,16 +323,9 @@ impl<'a> RefCodeGen<'a> {
                 &*(raw as *const str as *const Self)
             }
 
-            #[inline]
-            #[doc = #static_doc_comment]
-            #[doc = ""]
-            #[doc = "# Panics"]
-            #[doc = ""]
-            #[doc = "This function will panic if the provided raw string is not valid."]
-            #[track_caller]
-            pub fn from_static(raw: &'static str) -> &'static Self {
-                Self::from_str(raw).expect(concat!("invalid ", stringify!(#ty)))
-            }
+            #from_str_with
+
+            #from_static
 
             #into_owned
         }
@@ -270,6 +380,8 @@ impl<'a> RefCodeGen<'a> {
 
         let validator = crate::as_validator(normalizer);
         let normalizer = crate::as_normalizer(normalizer);
+        let error = self.impls.secret.error_type(&validator);
+        let map_err = self.impls.secret.map_error(&validator, &self.ident, core);
 
         let into_owned = self.owned_ty.map(|owned_ty| {
             let into_owned_doc = format!(
@@ -284,8 +396,8 @@ impl<'a> RefCodeGen<'a> {
                 #[allow(unsafe_code)]
                 #[inline]
                 #[doc = #doc_comment]
-                pub fn from_str(raw: &str) -> ::#core::result::Result<::#alloc::borrow::Cow<Self>, #validator::Error> {
-                    let cow = #normalizer::normalize(raw)?;
+                pub fn from_str(raw: &str) -> ::#core::result::Result<::#alloc::borrow::Cow<Self>, #error> {
+                    let cow = #normalizer::normalize(raw)#map_err?;
                     #unchecked_safety_comment
                     ::#core::result::Result::Ok(unsafe { Self::from_cow_str_unchecked(cow) })
                 }
@@ -324,8 +436,8 @@ impl<'a> RefCodeGen<'a> {
             #[allow(unsafe_code)]
             #[inline]
             #[doc = #doc_comment_norm]
-            pub fn from_normalized_str(raw: &str) -> ::#core::result::Result<&Self, #validator::Error> {
-                #validator::validate(raw)?;
+            pub fn from_normalized_str(raw: &str) -> ::#core::result::Result<&Self, #error> {
+                #validator::validate(raw)#map_err?;
                 #unchecked_safety_comment
                 ::#core::result::Result::Ok(unsafe { Self::from_str_unchecked(raw) })
             }
@@ -365,23 +477,52 @@ impl<'a> RefCodeGen<'a> {
                     quote! { #owned_ty { #field_name: self.#field_name.into() } }
                 }
             };
+            let create = if self.impls.secret.is_scrubbed() {
+                quote! { #owned_ty::scrub_registered(#create) }
+            } else {
+                create
+            };
 
-            quote! {
-                #[automatically_derived]
-                impl ::#alloc::borrow::ToOwned for #ty {
-                    type Owned = #owned_ty;
+            let field_name = &self.field.name;
+            let compare = |lhs: proc_macro2::TokenStream, rhs: proc_macro2::TokenStream| {
+                if self.impls.eq.is_constant_time() {
+                    quote! { ::aliri_braid::constant_time_eq(#lhs, #rhs) }
+                } else {
+                    quote! { #lhs == #rhs }
+                }
+            };
+            let owned_eq_ref = compare(
+                quote! { ::#core::convert::AsRef::<str>::as_ref(&self.#field_name) },
+                quote! { &other.#field_name },
+            );
+            let ref_eq_owned = compare(
+                quote! { &self.#field_name },
+                quote! { ::#core::convert::AsRef::<str>::as_ref(&other.#field_name) },
+            );
 
-                    #[inline]
-                    fn to_owned(&self) -> Self::Owned {
-                        #create
+            // `once` secrets are not `ToOwned`, so their value cannot be copied into a new owner
+            let to_owned = (!self.impls.secret.is_once()).then(|| {
+                quote! {
+                    #[automatically_derived]
+                    impl ::#alloc::borrow::ToOwned for #ty {
+                        type Owned = #owned_ty;
+
+                        #[inline]
+                        fn to_owned(&self) -> Self::Owned {
+                            #create
+                        }
                     }
                 }
+            });
+
+            quote! {
+                #to_owned
 
                 #[automatically_derived]
                 impl ::#core::cmp::PartialEq<#ty> for #owned_ty {
                     #[inline]
                     fn eq(&self, other: &#ty) -> bool {
-                        self.as_str() == other.as_str()
+                        #owned_eq_ref
                     }
                 }
 
@@ -389,7 +530,7 @@ impl<'a> RefCodeGen<'a> {
                 impl ::#core::cmp::PartialEq<#owned_ty> for #ty {
                     #[inline]
                     fn eq(&self, other: &#owned_ty) -> bool {
-                        self.as_str() == other.as_str()
+                        #ref_eq_owned
                     }
                 }
 
@@ -397,7 +538,7 @@ impl<'a> RefCodeGen<'a> {
                 impl ::#core::cmp::PartialEq<&'_ #ty> for #owned_ty {
                     #[inline]
                     fn eq(&self, other: &&#ty) -> bool {
-                        self.as_str() == other.as_str()
+                        #owned_eq_ref
                     }
                 }
 
@@ -405,7 +546,7 @@ impl<'a> RefCodeGen<'a> {
                 impl ::#core::cmp::PartialEq<#owned_ty> for &'_ #ty {
                     #[inline]
                     fn eq(&self, other: &#owned_ty) -> bool {
-                        self.as_str() == other.as_str()
+                        #ref_eq_owned
                     }
                 }
             }
@@ -418,6 +559,20 @@ impl<'a> RefCodeGen<'a> {
         let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
         let pointer_reinterpret_safety_comment = self.pointer_reinterpret_safety_comment(false);
+        let audit = self.impls.secret.audit(&self.ident, "AsStr");
+
+        let borrow_str = (!self.impls.secret.is_strict()).then(|| {
+            quote! {
+                #[automatically_derived]
+                impl ::#core::borrow::Borrow<str> for #ty {
+                    #[inline]
+                    fn borrow(&self) -> &str {
+                        #audit
+                        &self.#field_name
+                    }
+                }
+            }
+        });
 
         let from_str = match &self.check_mode {
             CheckMode::None => quote! {
@@ -429,20 +584,15 @@ impl<'a> RefCodeGen<'a> {
                     }
                 }
 
-                #[automatically_derived]
-                impl ::#core::borrow::Borrow<str> for #ty {
-                    #[inline]
-                    fn borrow(&self) -> &str {
-                        &self.#field_name
-                    }
-                }
+                #borrow_str
             },
             CheckMode::Validate(validator) => {
                 let validator = crate::as_validator(validator);
+                let error = self.impls.secret.error_type(&validator);
                 quote! {
                     #[automatically_derived]
                     impl<'a> ::#core::convert::TryFrom<&'a str> for &'a #ty {
-                        type Error = #validator::Error;
+                        type Error = #error;
 
                         #[inline]
                         fn try_from(s: &'a str) -> ::#core::result::Result<&'a #ty, Self::Error> {
@@ -450,21 +600,16 @@ impl<'a> RefCodeGen<'a> {
                         }
                     }
 
-                    #[automatically_derived]
-                    impl ::#core::borrow::Borrow<str> for #ty {
-                        #[inline]
-                        fn borrow(&self) -> &str {
-                            &self.#field_name
-                        }
-                    }
+                    #borrow_str
                 }
             }
             CheckMode::Normalize(normalizer) => {
                 let validator = crate::as_validator(normalizer);
+                let error = self.impls.secret.error_type(&validator);
                 quote! {
                     #[automatically_derived]
                     impl<'a> ::#core::convert::TryFrom<&'a str> for &'a #ty {
-                        type Error = #validator::Error;
+                        type Error = #error;
 
                         #[inline]
                         fn try_from(s: &'a str) -> ::#core::result::Result<&'a #ty, Self::Error> {
@@ -475,7 +620,7 @@ impl<'a> RefCodeGen<'a> {
             }
         };
 
-        let alloc_from = self.owned_ty.is_some().then(|| {
+        let alloc_from = (self.owned_ty.is_some() && !self.impls.secret.is_once()).then(|| {
             quote!{
                 #[automatically_derived]
                 impl<'a> ::#core::convert::From<&'a #ty> for ::#alloc::borrow::Cow<'a, #ty> {
@@ -500,7 +645,7 @@ impl<'a> RefCodeGen<'a> {
                     #[inline]
                     fn from(r: &'_ #ty) -> Self {
                         #pointer_reinterpret_safety_comment
-                        let rc = ::#alloc::rc::Rc::<str>::from(r.as_str());
+                        let rc = ::#alloc::rc::Rc::<str>::from(&r.#field_name);
                         unsafe { ::#alloc::rc::Rc::from_raw(::#alloc::rc::Rc::into_raw(rc) as *const #ty) }
                     }
                 }
@@ -511,23 +656,30 @@ impl<'a> RefCodeGen<'a> {
                     #[inline]
                     fn from(r: &'_ #ty) -> Self {
                         #pointer_reinterpret_safety_comment
-                        let arc = ::#alloc::sync::Arc::<str>::from(r.as_str());
+                        let arc = ::#alloc::sync::Arc::<str>::from(&r.#field_name);
                         unsafe { ::#alloc::sync::Arc::from_raw(::#alloc::sync::Arc::into_raw(arc) as *const #ty) }
                     }
                 }
             }
         });
 
+        let as_ref_str = (!self.impls.secret.is_strict()).then(|| {
+            quote! {
+                #[automatically_derived]
+                impl ::#core::convert::AsRef<str> for #ty {
+                    #[inline]
+                    fn as_ref(&self) -> &str {
+                        #audit
+                        &self.#field_name
+                    }
+                }
+            }
+        });
+
         quote! {
             #from_str
 
-            #[automatically_derived]
-            impl ::#core::convert::AsRef<str> for #ty {
-                #[inline]
-                fn as_ref(&self) -> &str {
-                    &self.#field_name
-                }
-            }
+            #as_ref_str
 
             #alloc_from
         }
@@ -536,10 +688,12 @@ impl<'a> RefCodeGen<'a> {
     pub fn tokens(&self) -> proc_macro2::TokenStream {
         let inherent = self.inherent();
         let comparison = self.comparison();
+        let eq = self.impls.eq.to_borrowed_impl(self);
         let conversion = self.conversion();
         let debug = self.impls.debug.to_borrowed_impl(self);
         let display = self.impls.display.to_borrowed_impl(self);
         let secret = self.impls.secret.to_borrowed_impl(self);
+        let zeroize = self.impls.zeroize.to_borrowed_impl(self);
         let ord = self.impls.ord.to_borrowed_impl(self);
         let serde = self.impls.serde.to_borrowed_impl(self);
 
@@ -569,8 +723,8 @@ impl<'a> RefCodeGen<'a> {
         };
 
         quote! {
+            #eq
             #[repr(transparent)]
-            #[derive(Hash, PartialEq, Eq)]
             #ord
             #ref_doc
             #ref_attrs
@@ -583,6 +737,7 @@ impl<'a> RefCodeGen<'a> {
             #debug
             #display
             #secret
+            #zeroize
             #serde
         }
     }
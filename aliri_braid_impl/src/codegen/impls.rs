@@ -1,22 +1,31 @@
-use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 
 use super::{check_mode::CheckMode, OwnedCodeGen, RefCodeGen};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ImplOption {
     Implement,
+    /// The trait is not implemented, but an equivalent inherent method is provided instead
+    Explicit,
     Omit,
 }
 
 impl ImplOption {
+    fn is_implement(self) -> bool {
+        matches!(self, Self::Implement)
+    }
+
+    fn is_explicit(self) -> bool {
+        matches!(self, Self::Explicit)
+    }
+
     fn map<F>(self, f: F) -> Option<proc_macro2::TokenStream>
     where
         F: FnOnce() -> proc_macro2::TokenStream,
     {
         match self {
             Self::Implement => Some(f()),
-            Self::Omit => None,
+            Self::Explicit | Self::Omit => None,
         }
     }
 }
@@ -79,7 +88,7 @@ impl From<ImplOption> for DelegatingImplOption {
     fn from(opt: ImplOption) -> Self {
         match opt {
             ImplOption::Implement => Self::Implement,
-            ImplOption::Omit => Self::Omit,
+            ImplOption::Explicit | ImplOption::Omit => Self::Omit,
         }
     }
 }
@@ -87,8 +96,10 @@ impl From<ImplOption> for DelegatingImplOption {
 #[derive(Debug, Default)]
 pub struct Impls {
     pub clone: ImplClone,
+    pub eq: ImplEq,
     pub debug: ImplDebug,
     pub secret: ImplSecret,
+    pub zeroize: ImplZeroize,
     pub display: ImplDisplay,
     pub ord: ImplOrd,
     pub serde: ImplSerde,
@@ -119,9 +130,129 @@ impl From<ImplOption> for ImplClone {
     }
 }
 
+impl std::str::FromStr for ImplClone {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "explicit" => Ok(Self(ImplOption::Explicit)),
+            _ => s
+                .parse::<ImplOption>()
+                .map(Self)
+                .map_err(|_| "valid values are: `impl`, `explicit`, or `omit`"),
+        }
+    }
+}
+
+impl ImplClone {
+    pub fn is_explicit(&self) -> bool {
+        self.0.is_explicit()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.0.is_implement() || self.0.is_explicit()
+    }
+}
+
 impl ToImpl for ImplClone {
-    fn to_owned_impl(&self, _gen: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
-        self.0.map(|| quote! { #[derive(Clone)] })
+    fn to_owned_impl(&self, gen: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            if !gen.impls.secret.is_scrubbed() {
+                return quote! { #[derive(Clone)] };
+            }
+
+            let ty = gen.ty;
+            let field_name = &gen.field.name;
+            let param = gen.field.name.input_name();
+            let create = gen.create();
+            let core = gen.std_lib.core();
+            quote! {
+                #[automatically_derived]
+                impl ::#core::clone::Clone for #ty {
+                    #[inline]
+                    fn clone(&self) -> Self {
+                        let #param = ::#core::clone::Clone::clone(&self.#field_name);
+                        #create
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ImplEq {
+    #[default]
+    Derive,
+    ConstantTime,
+}
+
+impl ImplEq {
+    pub fn is_constant_time(self) -> bool {
+        matches!(self, Self::ConstantTime)
+    }
+}
+
+impl ToImpl for ImplEq {
+    fn to_owned_impl(&self, gen: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        let ty = gen.ty;
+        let field_name = &gen.field.name;
+        let core = gen.std_lib.core();
+        Some(match self {
+            Self::Derive => quote! { #[derive(Hash, PartialEq, Eq)] },
+            Self::ConstantTime => quote! {
+                #[automatically_derived]
+                impl ::#core::hash::Hash for #ty {
+                    #[inline]
+                    fn hash<H: ::#core::hash::Hasher>(&self, state: &mut H) {
+                        <str as ::#core::hash::Hash>::hash(::#core::convert::AsRef::as_ref(&self.#field_name), state)
+                    }
+                }
+
+                #[automatically_derived]
+                impl ::#core::cmp::PartialEq for #ty {
+                    #[inline]
+                    fn eq(&self, other: &Self) -> bool {
+                        ::aliri_braid::constant_time_eq(
+                            ::#core::convert::AsRef::as_ref(&self.#field_name),
+                            ::#core::convert::AsRef::as_ref(&other.#field_name),
+                        )
+                    }
+                }
+
+                #[automatically_derived]
+                impl ::#core::cmp::Eq for #ty {}
+            },
+        })
+    }
+
+    fn to_borrowed_impl(&self, gen: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
+        let ty = &gen.ty;
+        let field_name = &gen.field.name;
+        let core = gen.std_lib.core();
+        Some(match self {
+            Self::Derive => quote! { #[derive(Hash, PartialEq, Eq)] },
+            Self::ConstantTime => quote! {
+                #[automatically_derived]
+                impl ::#core::hash::Hash for #ty {
+                    #[inline]
+                    fn hash<H: ::#core::hash::Hasher>(&self, state: &mut H) {
+                        <str as ::#core::hash::Hash>::hash(&self.#field_name, state)
+                    }
+                }
+
+                #[automatically_derived]
+                impl ::#core::cmp::PartialEq for #ty {
+                    #[inline]
+                    fn eq(&self, other: &Self) -> bool {
+                        ::aliri_braid::constant_time_eq(&self.#field_name, &other.#field_name)
+                    }
+                }
+
+                #[automatically_derived]
+                impl ::#core::cmp::Eq for #ty {}
+            },
+        })
     }
 }
 
@@ -227,75 +358,353 @@ impl ToImpl for ImplDebug {
 }
 
 #[derive(Debug)]
-pub struct ImplSecret(DelegatingImplOption);
+pub struct ImplSecret {
+    mode: DelegatingImplOption,
+    strict: bool,
+    pub debug: Redaction,
+    pub display: Redaction,
+    pub audit: Option<proc_macro2::TokenStream>,
+    pub load: Option<proc_macro2::Span>,
+    pub scrub: Option<proc_macro2::Span>,
+    pub once: Option<proc_macro2::Span>,
+}
 
 impl Default for ImplSecret {
     fn default() -> Self {
-        Self(DelegatingImplOption::Omit)
+        DelegatingImplOption::Omit.into()
     }
 }
 
 impl From<DelegatingImplOption> for ImplSecret {
-    fn from(opt: DelegatingImplOption) -> Self {
-        Self(opt)
+    fn from(mode: DelegatingImplOption) -> Self {
+        Self {
+            mode,
+            strict: false,
+            debug: Redaction::default(),
+            display: Redaction::default(),
+            audit: None,
+            load: None,
+            scrub: None,
+            once: None,
+        }
+    }
+}
+
+impl std::str::FromStr for ImplSecret {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "strict" => Ok(Self::strict()),
+            _ => s
+                .parse::<DelegatingImplOption>()
+                .map(Self::from)
+                .map_err(|_| "valid values are: `impl`, `owned`, `omit`, or `strict`"),
+        }
+    }
+}
+
+impl ImplSecret {
+    pub fn strict() -> Self {
+        let mut secret = Self::from(DelegatingImplOption::Implement);
+        secret.set_strict();
+        secret
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.mode != DelegatingImplOption::Omit
+    }
+
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Requires explicit access to the value, which is then never revealed by the alternate flag
+    pub fn set_strict(&mut self) {
+        self.strict = true;
+        self.debug.reveal = false;
+        self.display.reveal = false;
+    }
+
+    /// Generates a call to the audit hook, if any, reporting an access of the given kind
+    pub fn audit(
+        &self,
+        ty: &dyn std::fmt::Display,
+        access: &str,
+    ) -> Option<proc_macro2::TokenStream> {
+        self.audit.as_ref().map(|hook| {
+            let ty = ty.to_string();
+            let access = proc_macro2::Ident::new(access, proc_macro2::Span::call_site());
+            quote! {
+                <#hook as ::aliri_braid::SecretAccessHook>::on_access(#ty, ::aliri_braid::SecretAccess::#access);
+            }
+        })
+    }
+
+    /// The error type produced when the validator rejects a value, which is redacted for secrets
+    pub fn error_type(&self, validator: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        if self.is_enabled() {
+            quote! { ::aliri_braid::redaction::RedactedError<#validator::Error> }
+        } else {
+            quote! { #validator::Error }
+        }
+    }
+
+    /// Converts an error into the validator's error type, annotated with the braid's details,
+    /// and then into the redacted error type for secrets
+    pub fn map_error(
+        &self,
+        validator: &proc_macro2::TokenStream,
+        ty: &dyn std::fmt::Display,
+        core: &proc_macro2::Ident,
+    ) -> proc_macro2::TokenStream {
+        let convert = self.convert_error(validator, ty, core);
+        quote! { .map_err(#convert) }
+    }
+
+    /// A closure performing the conversion of [`map_error()`](Self::map_error)
+    pub fn convert_error(
+        &self,
+        validator: &proc_macro2::TokenStream,
+        ty: &dyn std::fmt::Display,
+        core: &proc_macro2::Ident,
+    ) -> proc_macro2::TokenStream {
+        let ty = ty.to_string();
+        let secret = self.is_enabled();
+        let annotated = quote! {
+            #validator::annotate_error(::#core::convert::From::from(e), #ty, #secret)
+        };
+
+        if secret {
+            quote! {
+                |e| ::aliri_braid::redaction::RedactedError::new(#ty, #annotated)
+            }
+        } else {
+            quote! {
+                |e| #annotated
+            }
+        }
+    }
+
+    /// Implements `StaticRedaction` with the `Display` redaction of the secret
+    fn static_redaction(
+        &self,
+        ty: &dyn ToTokens,
+        value: &proc_macro2::TokenStream,
+        core: &proc_macro2::Ident,
+    ) -> proc_macro2::TokenStream {
+        let ty_name = ty.to_token_stream().to_string();
+        let redacted = self.display.redacted_statically(&ty_name, value);
+        quote! {
+            #[automatically_derived]
+            impl ::aliri_braid::redaction::StaticRedaction for #ty {
+                #[inline]
+                fn fmt_static_redaction(&self, f: &mut ::#core::fmt::Formatter<'_>) -> ::#core::fmt::Result {
+                    #redacted
+                }
+            }
+        }
+    }
+
+    pub fn is_once(&self) -> bool {
+        self.once.is_some()
+    }
+
+    pub fn is_scrubbed(&self) -> bool {
+        self.scrub.is_some()
+    }
+
+    /// Generates a call removing the value from the global scrubber, if scrubbed
+    pub fn scrub_unregister(
+        &self,
+        value: &proc_macro2::TokenStream,
+    ) -> Option<proc_macro2::TokenStream> {
+        self.is_scrubbed().then(|| {
+            quote! {
+                ::aliri_braid::Scrubber::global().remove(#value);
+            }
+        })
+    }
+
+    pub fn is_fingerprinted(&self) -> bool {
+        matches!(self.debug.strategy, RedactionStrategy::Fingerprint)
+            || matches!(self.display.strategy, RedactionStrategy::Fingerprint)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub enum RedactionStrategy {
+    #[default]
+    TypeName,
+    Message(String),
+    Last(usize),
+    LengthOnly,
+    Fingerprint,
+}
+
+#[derive(Clone, Debug)]
+pub struct Redaction {
+    pub strategy: RedactionStrategy,
+    pub reveal: bool,
+}
+
+impl Default for Redaction {
+    fn default() -> Self {
+        Self {
+            strategy: RedactionStrategy::default(),
+            reveal: true,
+        }
+    }
+}
+
+impl Redaction {
+    fn redacted(
+        &self,
+        ty: &dyn std::fmt::Display,
+        value: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match &self.strategy {
+            RedactionStrategy::TypeName => {
+                let msg = format!("[redacted {ty}]");
+                quote! { f.write_str(#msg) }
+            }
+            RedactionStrategy::Message(msg) => quote! { f.write_str(#msg) },
+            RedactionStrategy::Last(visible) => {
+                quote! { ::aliri_braid::redaction::write_masked(f, #value, #visible) }
+            }
+            RedactionStrategy::LengthOnly => {
+                quote! { ::aliri_braid::redaction::write_length_only(f, #value) }
+            }
+            RedactionStrategy::Fingerprint => {
+                let ty = ty.to_string();
+                quote! { ::aliri_braid::redaction::write_fingerprinted(f, #ty, #value) }
+            }
+        }
+    }
+
+    /// Writes the redaction without consulting the runtime policy or including a fingerprint
+    pub fn redacted_statically(
+        &self,
+        ty: &dyn std::fmt::Display,
+        value: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match &self.strategy {
+            RedactionStrategy::Fingerprint => Redaction::default().redacted(ty, value),
+            _ => self.redacted(ty, value),
+        }
     }
 }
 
 #[rustfmt::skip]
 macro_rules! impl_secret {
-    (@owned, $ty:ident, $ref_ty:ident, $field:ident, $core:ident, $msg:expr) => {{
+    ($secret:expr, $ty:ident, $value:expr, $core:ident) => {{
+        let value = $value;
         let mut tokens = proc_macro2::TokenStream::new();
+        let ty_name = $ty.to_token_stream().to_string();
+        let fingerprinted = quote! {
+            ::aliri_braid::redaction::write_fingerprinted(f, #ty_name, #value)
+        };
+        let audit = $secret.audit(&ty_name, "Format");
+        // The values of `strict` secrets, including `once` secrets, are never revealed by
+        // formatting, even under the runtime policy
+        let display_redacted = $secret.display.redacted(&ty_name, &value);
+        let debug_redacted = $secret.debug.redacted(&ty_name, &value);
         tokens.extend(impl_secret!(
-            @impl, Display, $ty, $core, $msg,
-            quote!(<#$ref_ty as ::#$core::fmt::Display>::fmt(::#$core::ops::Deref::deref(self), f))
+            @impl, Display, $ty, $core,
+            display_redacted.clone(),
+            &fingerprinted,
+            $secret.display.reveal.then(|| quote! {
+                #audit
+                <str as ::#$core::fmt::Display>::fmt(#value, f)
+            }),
+            if $secret.is_strict() {
+                display_redacted
+            } else {
+                quote! {{
+                    #audit
+                    <str as ::#$core::fmt::Display>::fmt(#value, f)
+                }}
+            },
         ));
-        tokens.extend(impl_secret!(@impl, Debug, $ty, $field, $core, $msg));
-        tokens
-    }};
-    (@borrowed, $ty:ident, $field:ident, $core:ident, $msg:expr) => {{
-        let mut tokens = proc_macro2::TokenStream::new();
         tokens.extend(impl_secret!(
-            @impl, Display, $ty, $core, $msg,
-            quote!(<str as ::#$core::fmt::Display>::fmt(&self.#$field, f)),
+            @impl, Debug, $ty, $core,
+            debug_redacted.clone(),
+            &fingerprinted,
+            $secret.debug.reveal.then(|| {
+                let reveal = impl_secret!(@reveal, Debug, value);
+                quote! {
+                    #audit
+                    #reveal
+                }
+            }),
+            if $secret.is_strict() {
+                debug_redacted
+            } else {
+                quote! {{
+                    #audit
+                    <str as ::#$core::fmt::Debug>::fmt(#value, f)
+                }}
+            },
         ));
-        tokens.extend(impl_secret!(@impl, Debug, $ty, $field, $core, $msg));
         tokens
     }};
-    (@impl, Debug, $ty:ident, $field:ident, $core:ident, $msg:expr) => {
-        impl_secret!(
-            @impl, Debug, $ty, $core, $msg,
-            quote! {
-                f.write_str("\"")?;
-                let max_len = f.width().unwrap_or(10);
-                if max_len <= 1 {
-                    f.write_str("…")?;
-                } else {
-                    match self.#$field.char_indices().nth(max_len - 2) {
-                        Some((idx, c)) if idx + c.len_utf8() < self.#$field.len() => {
-                            f.write_str(&self.#$field[0..idx + c.len_utf8()])?;
-                            f.write_str("…")?;
-                        }
-                        _ => {
-                            f.write_str(&self.#$field)?;
-                        }
+    (@reveal, Debug, $value:expr) => {{
+        let value = $value;
+        quote! {
+            let value: &str = #value;
+            f.write_str("\"")?;
+            let max_len = f.width().unwrap_or(10);
+            if max_len <= 1 {
+                f.write_str("…")?;
+            } else {
+                match value.char_indices().nth(max_len - 2) {
+                    Some((idx, c)) if idx + c.len_utf8() < value.len() => {
+                        f.write_str(&value[0..idx + c.len_utf8()])?;
+                        f.write_str("…")?;
+                    }
+                    _ => {
+                        f.write_str(value)?;
                     }
                 }
-                f.write_str("\"")
-            },
-        )
-    };
-    (@impl, $trait:ident, $ty:ident, $core:ident, $msg:expr, $alternate:expr $(,)?) => {{
-        let msg = $msg;
-        let alternate = $alternate;
+            }
+            f.write_str("\"")
+        }
+    }};
+    (
+        @impl, $trait:ident, $ty:ident, $core:ident,
+        $redacted:expr, $fingerprinted:expr, $alternate:expr, $revealed:expr $(,)?
+    ) => {{
+        let redacted = $redacted;
+        let fingerprinted = $fingerprinted;
+        let revealed = $revealed;
+        let (redacted, fingerprinted) = match $alternate {
+            Some(alternate) => (
+                quote! {
+                    if f.alternate() {
+                        #alternate
+                    } else {
+                        #redacted
+                    }
+                },
+                quote! {
+                    if f.alternate() {
+                        #alternate
+                    } else {
+                        #fingerprinted
+                    }
+                },
+            ),
+            None => (redacted, fingerprinted.clone()),
+        };
         quote! {
             #[automatically_derived]
             impl ::#$core::fmt::$trait for #$ty {
                 #[inline]
                 fn fmt(&self, f: &mut ::#$core::fmt::Formatter) -> ::#$core::fmt::Result {
-                    if f.alternate() {
-                        #alternate
-                    } else {
-                        f.write_str(#msg)
+                    match ::aliri_braid::redaction::policy() {
+                        ::aliri_braid::redaction::Policy::Reveal => #revealed,
+                        ::aliri_braid::redaction::Policy::Fingerprint => #fingerprinted,
+                        _ => #redacted,
                     }
                 }
             }
@@ -307,20 +716,117 @@ impl ToImpl for ImplSecret {
     fn to_owned_impl(&self, gen: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
         let ty = gen.ty;
         let field_name = &gen.field.name;
-        let ref_ty = gen.ref_ty;
         let core = gen.std_lib.core();
-        let msg = format!("[redacted {ty}]");
-        self.0
-            .map_owned(|| impl_secret!(@owned, ty, ref_ty, field_name, core, &msg))
+        let value = quote! { ::#core::convert::AsRef::<str>::as_ref(&self.#field_name) };
+
+        // With `zeroize`, the value is unregistered by the `Drop` implementation that wipes it
+        let unregister_on_drop = (!gen.impls.zeroize.is_enabled())
+            .then(|| self.scrub_unregister(&value))
+            .flatten()
+            .map(|unregister| {
+                quote! {
+                    #[automatically_derived]
+                    impl ::#core::ops::Drop for #ty {
+                        #[inline]
+                        fn drop(&mut self) {
+                            #unregister
+                        }
+                    }
+                }
+            });
+
+        let fmt = self.mode.map_owned(|| {
+            let static_redaction = self.static_redaction(ty, &value, core);
+            let fmt = impl_secret!(self, ty, value, core);
+            quote! {
+                #fmt
+                #static_redaction
+            }
+        });
+        if fmt.is_none() && unregister_on_drop.is_none() {
+            return None;
+        }
+
+        Some(quote! {
+            #fmt
+            #unregister_on_drop
+        })
     }
 
     fn to_borrowed_impl(&self, gen: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
         let ident = &gen.ident;
         let field_name = &gen.field.name;
         let core = gen.std_lib.core();
-        let msg = format!("[redacted {ident}]");
-        self.0
-            .map_ref(|| impl_secret!(@borrowed, ident, field_name, core, &msg))
+        let value = quote! { &self.#field_name };
+        self.mode.map_ref(|| {
+            let static_redaction = self.static_redaction(ident, &value, core);
+            let fmt = impl_secret!(self, ident, value, core);
+            quote! {
+                #fmt
+                #static_redaction
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ImplZeroize(ImplOption);
+
+impl Default for ImplZeroize {
+    fn default() -> Self {
+        Self(ImplOption::Omit)
+    }
+}
+
+impl From<ImplOption> for ImplZeroize {
+    fn from(opt: ImplOption) -> Self {
+        Self(opt)
+    }
+}
+
+impl ImplZeroize {
+    pub fn is_enabled(&self) -> bool {
+        self.0.is_implement()
+    }
+}
+
+impl ToImpl for ImplZeroize {
+    fn to_owned_impl(&self, gen: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        let ty = gen.ty;
+        let field_name = &gen.field.name;
+        let core = gen.std_lib.core();
+        let unregister = gen.impls.secret.scrub_unregister(
+            &quote! { ::#core::convert::AsRef::<str>::as_ref(&self.#field_name) },
+        );
+        self.0.map(|| {
+            quote! {
+                #[automatically_derived]
+                impl ::#core::ops::Drop for #ty {
+                    #[inline]
+                    fn drop(&mut self) {
+                        #unregister
+                        ::aliri_braid::zeroize::Zeroize::zeroize(&mut self.#field_name);
+                    }
+                }
+            }
+        })
+    }
+
+    fn to_borrowed_impl(&self, gen: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
+        let ty = &gen.ty;
+        let field_name = &gen.field.name;
+        let core = gen.std_lib.core();
+        self.0.map(|| {
+            quote! {
+                #[automatically_derived]
+                impl ::#core::ops::Drop for #ty {
+                    #[inline]
+                    fn drop(&mut self) {
+                        ::aliri_braid::zeroize::Zeroize::zeroize(&mut self.#field_name);
+                    }
+                }
+            }
+        })
     }
 }
 
@@ -368,35 +874,228 @@ impl ToImpl for ImplOrd {
     }
 }
 
-#[derive(Debug)]
-pub struct ImplSerde(ImplOption);
-
-impl Default for ImplSerde {
-    fn default() -> Self {
-        Self(ImplOption::Omit)
-    }
+#[derive(Debug, Default)]
+pub enum ImplSerde {
+    Implement,
+    #[default]
+    Omit,
+    Expose,
+    Redact,
+    Refuse,
+    Sealed,
 }
 
 impl From<ImplOption> for ImplSerde {
     fn from(opt: ImplOption) -> Self {
-        Self(opt)
+        match opt {
+            ImplOption::Implement => Self::Implement,
+            ImplOption::Explicit | ImplOption::Omit => Self::Omit,
+        }
+    }
+}
+
+impl std::str::FromStr for ImplSerde {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "impl" => Ok(Self::Implement),
+            "omit" => Ok(Self::Omit),
+            "expose" => Ok(Self::Expose),
+            "redact" => Ok(Self::Redact),
+            "error" => Ok(Self::Refuse),
+            "sealed" => Ok(Self::Sealed),
+            _ => Err("valid values are: `impl`, `omit`, `expose`, `redact`, `error`, or `sealed`"),
+        }
+    }
+}
+
+impl ImplSerde {
+    pub fn is_implement(&self) -> bool {
+        matches!(self, Self::Implement)
+    }
+
+    pub fn is_expose(&self) -> bool {
+        matches!(self, Self::Expose)
+    }
+
+    pub fn is_sealed(&self) -> bool {
+        matches!(self, Self::Sealed)
+    }
+
+    pub fn is_secret_mode(&self) -> bool {
+        matches!(
+            self,
+            Self::Expose | Self::Redact | Self::Refuse | Self::Sealed
+        )
+    }
+
+    /// Generates a `DeserializeSeed` that deserializes the owned type as usual, and then checks
+    /// it against the context of its `ContextValidator`
+    fn context_seed(gen: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        let CheckMode::Validate(validator) = gen.check_mode else {
+            return None;
+        };
+        if !gen.context_validator {
+            return None;
+        }
+
+        let name = gen.ty;
+        let seed = format_ident!("{}Seed", name);
+        let vis = &gen.body.vis;
+        let field_name = &gen.field.name;
+        let core = gen.std_lib.core();
+        let doc = format!(
+            "Deserializes a [`{name}`], including the rules of [`{}`] that depend on the \
+             context\n\nUse with \
+             [`DeserializeSeed::deserialize()`](::serde::de::DeserializeSeed::deserialize).",
+            validator.to_token_stream(),
+        );
+
+        let context_validator = crate::as_context_validator(validator);
+        let validator = crate::as_validator(validator);
+        let map_err = gen.impls.secret.map_error(&validator, name, core);
+
+        Some(quote! {
+            #[doc = #doc]
+            #[derive(Clone, Copy)]
+            #vis struct #seed<'c>(pub &'c #context_validator::Context);
+
+            #[automatically_derived]
+            impl ::#core::fmt::Debug for #seed<'_> {
+                fn fmt(&self, f: &mut ::#core::fmt::Formatter) -> ::#core::fmt::Result {
+                    f.write_str(concat!(stringify!(#seed), "(..)"))
+                }
+            }
+
+            #[automatically_derived]
+            impl<'de> ::serde::de::DeserializeSeed<'de> for #seed<'_> {
+                type Value = #name;
+
+                fn deserialize<D: ::serde::Deserializer<'de>>(self, deserializer: D) -> ::#core::result::Result<#name, D::Error> {
+                    let value = <#name as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
+                    #context_validator::validate_with(
+                        self.0,
+                        ::#core::convert::AsRef::<str>::as_ref(&value.#field_name),
+                    )
+                    #map_err
+                    .map_err(<D::Error as ::serde::de::Error>::custom)?;
+                    ::#core::result::Result::Ok(value)
+                }
+            }
+        })
+    }
+
+    fn map<F>(&self, f: F) -> Option<proc_macro2::TokenStream>
+    where
+        F: FnOnce() -> proc_macro2::TokenStream,
+    {
+        match self {
+            Self::Omit => None,
+            _ => Some(f()),
+        }
+    }
+
+    fn serialize_body(
+        &self,
+        ty: &dyn std::fmt::Display,
+        sealed_ty: &dyn std::fmt::Display,
+        value: proc_macro2::TokenStream,
+        secret: &ImplSecret,
+        core: &proc_macro2::Ident,
+    ) -> proc_macro2::TokenStream {
+        match self {
+            Self::Redact => {
+                let redacted = secret.display.redacted(ty, &quote! { self.0 });
+                quote! {
+                    struct Redacted<'a>(&'a str);
+
+                    impl ::#core::fmt::Display for Redacted<'_> {
+                        fn fmt(&self, f: &mut ::#core::fmt::Formatter) -> ::#core::fmt::Result {
+                            #redacted
+                        }
+                    }
+
+                    serializer.collect_str(&Redacted(#value))
+                }
+            }
+            Self::Refuse => {
+                let msg = format!("refusing to serialize secret value of type `{ty}`");
+                quote! {
+                    let _ = serializer;
+                    ::#core::result::Result::Err(<S::Error as ::serde::ser::Error>::custom(#msg))
+                }
+            }
+            Self::Sealed => {
+                let audit = secret.audit(ty, "Serialize");
+                let sealed_ty = sealed_ty.to_string();
+                quote! {
+                    #audit
+                    let sealed = ::aliri_braid::seal::seal(#sealed_ty, #value)
+                        .map_err(<S::Error as ::serde::ser::Error>::custom)?;
+                    serializer.serialize_str(&sealed)
+                }
+            }
+            Self::Implement | Self::Expose | Self::Omit => {
+                let audit = secret.audit(ty, "Serialize");
+                quote! {
+                    #audit
+                    <str as ::serde::Serialize>::serialize(#value, serializer)
+                }
+            }
+        }
     }
 }
 
 impl ToImpl for ImplSerde {
     fn to_owned_impl(&self, gen: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
-        self.0.map(|| {
+        self.map(|| {
             let handle_failure = gen.check_mode.serde_err_handler();
 
             let name = gen.ty;
             let field_name = &gen.field.name;
             let wrapped_type = &gen.field.ty;
+            let core = gen.std_lib.core();
+
+            let serialize_body = match self {
+                Self::Implement | Self::Expose => {
+                    let audit = gen.impls.secret.audit(&name.to_token_stream(), "Serialize");
+                    quote! {
+                        #audit
+                        <#wrapped_type as ::serde::Serialize>::serialize(&self.#field_name, serializer)
+                    }
+                }
+                _ => self.serialize_body(
+                    &name.to_token_stream(),
+                    &name.to_token_stream(),
+                    quote! { ::#core::convert::AsRef::<str>::as_ref(&self.#field_name) },
+                    &gen.impls.secret,
+                    core,
+                ),
+            };
+
+            let deserialize_body = if self.is_sealed() {
+                let alloc = gen.std_lib.alloc();
+                let ty_name = name.to_string();
+                quote! {
+                    let raw = <::#alloc::string::String as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
+                    ::aliri_braid::seal::unseal(#ty_name, &raw, <Self as ::#core::str::FromStr>::from_str)
+                        .map_err(<D::Error as ::serde::de::Error>::custom)
+                }
+            } else {
+                quote! {
+                    let raw = <#wrapped_type as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
+                    Ok(Self::new(raw)#handle_failure)
+                }
+            };
+
+            let seed = Self::context_seed(gen);
 
             quote! {
                 #[automatically_derived]
                 impl ::serde::Serialize for #name {
                     fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-                        <#wrapped_type as ::serde::Serialize>::serialize(&self.#field_name, serializer)
+                        #serialize_body
                     }
                 }
 
@@ -404,17 +1103,19 @@ impl ToImpl for ImplSerde {
                 #[automatically_derived]
                 impl<'de> ::serde::Deserialize<'de> for #name {
                     fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-                        let raw = <#wrapped_type as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
-                        Ok(Self::new(raw)#handle_failure)
+                        #deserialize_body
                     }
                 }
+
+                #seed
             }
         })
     }
 
     fn to_borrowed_impl(&self, gen: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
-        self.0.map(|| {
+        self.map(|| {
             let ty = &gen.ty;
+            let field_name = &gen.field.name;
             let check_mode = gen.check_mode;
             let core = gen.std_lib.core();
             let alloc = gen.std_lib.alloc();
@@ -433,7 +1134,10 @@ impl ToImpl for ImplSerde {
                 }
             });
 
-            let deserialize = if matches!(check_mode, CheckMode::Normalize(_)) {
+            let deserialize = if self.is_sealed() {
+                // Sealed values are unsealed into a new allocation, so cannot be borrowed
+                None
+            } else if matches!(check_mode, CheckMode::Normalize(_)) {
                 let deserialize_doc = format!(
                     "Deserializes a `{ty}` in normalized form\n\
                     \n\
@@ -444,7 +1148,7 @@ impl ToImpl for ImplSerde {
                     owned = gen.owned_ty.expect("normalize not available if no owned").to_token_stream(),
                 );
 
-                quote! {
+                Some(quote! {
                     // impl<'de: 'a, 'a> ::serde::Deserialize<'de> for ::#alloc::borrow::Cow<'a, #name> {
                     //     fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::#core::result::Result<Self, D::Error> {
                     //         let raw = <&str as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
@@ -461,9 +1165,9 @@ impl ToImpl for ImplSerde {
                             ::#core::result::Result::Ok(#ty::from_normalized_str(raw)#handle_failure)
                         }
                     }
-                }
+                })
             } else {
-                quote! {
+                Some(quote! {
                     #[allow(clippy::needless_question_mark, clippy::unsafe_derive_deserialize)]
                     #[automatically_derived]
                     impl<'de: 'a, 'a> ::serde::Deserialize<'de> for &'a #ty {
@@ -472,14 +1176,23 @@ impl ToImpl for ImplSerde {
                             ::#core::result::Result::Ok(#ty::from_str(raw)#handle_failure)
                         }
                     }
-                }
+                })
             };
 
+            let sealed_ty = gen.owned_ty.unwrap_or(&gen.ident);
+            let serialize_body = self.serialize_body(
+                &gen.ident,
+                sealed_ty,
+                quote! { &self.#field_name },
+                &gen.impls.secret,
+                core,
+            );
+
             quote! {
                 #[automatically_derived]
                 impl ::serde::Serialize for #ty {
                     fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> ::#core::result::Result<S::Ok, S::Error> {
-                        <str as ::serde::Serialize>::serialize(self.as_str(), serializer)
+                        #serialize_body
                     }
                 }
 
@@ -1,17 +1,22 @@
 use quote::{format_ident, ToTokens, TokenStreamExt};
-use symbol::{parse_expr_as_lit, parse_lit_into_string, parse_lit_into_type};
+use symbol::{parse_expr_as_lit, parse_lit_into_string, parse_lit_into_type, parse_lit_into_usize};
 use syn::spanned::Spanned;
 
 pub use self::{borrowed::RefCodeGen, owned::OwnedCodeGen};
 use self::{
     check_mode::{CheckMode, IndefiniteCheckMode},
-    impls::{DelegatingImplOption, ImplOption, Impls},
+    impls::{
+        DelegatingImplOption, ImplClone, ImplEq, ImplOption, ImplSecret, ImplSerde, Impls,
+        Redaction, RedactionStrategy,
+    },
+    rules::Rules,
 };
 
 mod borrowed;
 mod check_mode;
 mod impls;
 mod owned;
+mod rules;
 mod symbol;
 
 pub type AttrList = syn::punctuated::Punctuated<syn::Meta, syn::Token![,]>;
@@ -37,6 +42,10 @@ impl StdLib {
     pub fn alloc(&self) -> &proc_macro2::Ident {
         &self.alloc
     }
+
+    pub fn is_no_std(&self) -> bool {
+        self.core == "core"
+    }
 }
 
 impl Default for StdLib {
@@ -55,7 +64,11 @@ pub struct Params {
     owned_attrs: AttrList,
     std_lib: StdLib,
     check_mode: IndefiniteCheckMode,
+    const_validator: bool,
+    context_validator: bool,
     expose_inner: bool,
+    generate: Option<Generate>,
+    rules: Option<Rules>,
     impls: Impls,
 }
 
@@ -68,7 +81,11 @@ impl Default for Params {
             owned_attrs: AttrList::new(),
             std_lib: StdLib::default(),
             check_mode: IndefiniteCheckMode::None,
+            const_validator: false,
+            context_validator: false,
             expose_inner: true,
+            generate: None,
+            rules: None,
             impls: Impls::default(),
         }
     }
@@ -77,6 +94,11 @@ impl Default for Params {
 impl syn::parse::Parse for Params {
     fn parse(input: syn::parse::ParseStream) -> Result<Self, syn::Error> {
         let mut params = Self::default();
+        let mut ord_specified = false;
+        let mut clone_specified = false;
+        let mut serde_arg = None;
+        let mut const_validator_arg = None;
+        let mut context_validator_arg = None;
         let args =
             syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated(input)?;
 
@@ -137,11 +159,16 @@ impl syn::parse::Parse for Params {
                 syn::Meta::NameValue(nv) if nv.path == symbol::SECRET => {
                     params.impls.secret =
                         parse_lit_into_string(symbol::SECRET, parse_expr_as_lit(&nv.value)?)?
-                            .parse::<DelegatingImplOption>()
-                            .map_err(|e| syn::Error::new_spanned(&arg, e.to_owned()))?
-                            .into();
+                            .parse::<ImplSecret>()
+                            .map_err(|e| syn::Error::new_spanned(&arg, e.to_owned()))?;
+                    params.impls.debug = DelegatingImplOption::Omit.into();
+                    params.impls.display = DelegatingImplOption::Omit.into();
+                }
+                syn::Meta::List(list) if list.path == symbol::SECRET => {
+                    params.impls.secret = DelegatingImplOption::Implement.into();
                     params.impls.debug = DelegatingImplOption::Omit.into();
                     params.impls.display = DelegatingImplOption::Omit.into();
+                    parse_secret_args(list, &mut params.impls)?;
                 }
                 syn::Meta::NameValue(nv) if nv.path == symbol::ORD => {
                     params.impls.ord =
@@ -149,23 +176,25 @@ impl syn::parse::Parse for Params {
                             .parse::<DelegatingImplOption>()
                             .map_err(|e| syn::Error::new_spanned(&arg, e.to_owned()))?
                             .into();
+                    ord_specified = true;
                 }
                 syn::Meta::NameValue(nv) if nv.path == symbol::CLONE => {
                     params.impls.clone =
                         parse_lit_into_string(symbol::CLONE, parse_expr_as_lit(&nv.value)?)?
-                            .parse::<ImplOption>()
-                            .map_err(|e| syn::Error::new_spanned(&arg, e.to_owned()))?
-                            .into();
+                            .parse::<ImplClone>()
+                            .map_err(|e| syn::Error::new_spanned(&arg, e.to_owned()))?;
+                    clone_specified = true;
                 }
                 syn::Meta::NameValue(nv) if nv.path == symbol::SERDE => {
                     params.impls.serde =
                         parse_lit_into_string(symbol::SERDE, parse_expr_as_lit(&nv.value)?)?
-                            .parse::<ImplOption>()
-                            .map_err(|e| syn::Error::new_spanned(&arg, e.to_owned()))?
-                            .into();
+                            .parse::<ImplSerde>()
+                            .map_err(|e| syn::Error::new_spanned(&arg, e.to_owned()))?;
+                    serde_arg = Some(arg.clone());
                 }
                 syn::Meta::Path(p) if p == symbol::SERDE => {
                     params.impls.serde = ImplOption::Implement.into();
+                    serde_arg = Some(arg.clone());
                 }
                 syn::Meta::Path(p) if p == symbol::VALIDATOR => {
                     params
@@ -185,6 +214,22 @@ impl syn::parse::Parse for Params {
                 syn::Meta::Path(p) if p == symbol::NO_EXPOSE => {
                     params.expose_inner = false;
                 }
+                syn::Meta::Path(p) if p == symbol::CONST_VALIDATOR => {
+                    params.const_validator = true;
+                    const_validator_arg = Some(p.clone());
+                }
+                syn::Meta::Path(p) if p == symbol::CONTEXT_VALIDATOR => {
+                    params.context_validator = true;
+                    context_validator_arg = Some(p.clone());
+                }
+                syn::Meta::List(list) if list.path == symbol::GENERATE => {
+                    params.generate = Some(Generate::parse(list)?);
+                }
+                syn::Meta::List(list) if list.path == symbol::VALIDATE => {
+                    params.rules = Some(Rules::parse(list)?);
+                    params.const_validator = true;
+                    set_rules_validator(&mut params.check_mode, list)?;
+                }
                 syn::Meta::Path(ref path)
                 | syn::Meta::NameValue(syn::MetaNameValue { ref path, .. }) => {
                     return Err(syn::Error::new_spanned(
@@ -201,6 +246,56 @@ impl syn::parse::Parse for Params {
             }
         }
 
+        apply_secret_defaults(
+            &mut params.impls,
+            ord_specified,
+            clone_specified,
+            serde_arg.as_ref(),
+        )?;
+        check_requires_validator(
+            &params.check_mode,
+            const_validator_arg,
+            symbol::CONST_VALIDATOR,
+        )?;
+        check_requires_validator(
+            &params.check_mode,
+            context_validator_arg,
+            symbol::CONTEXT_VALIDATOR,
+        )?;
+
+        // Normalization may return a borrowed value that must be copied into a new owner
+        if let (Some(once), IndefiniteCheckMode::Normalize(_)) =
+            (params.impls.secret.once, &params.check_mode)
+        {
+            return Err(syn::Error::new(
+                once,
+                "`once` secrets cannot be combined with `normalizer`",
+            ));
+        }
+
+        if params.std_lib.is_no_std() {
+            let secret = &params.impls.secret;
+            for (span, arg) in [(secret.load, "load"), (secret.scrub, "scrub")] {
+                if let Some(span) = span {
+                    return Err(syn::Error::new(
+                        span,
+                        format!(
+                            "`{arg}` requires the standard library and is not supported with \
+                             `no_std`"
+                        ),
+                    ));
+                }
+            }
+
+            if let Some(arg) = serde_arg.filter(|_| params.impls.serde.is_sealed()) {
+                return Err(syn::Error::new_spanned(
+                    arg,
+                    "`serde = \"sealed\"` requires the standard library and is not supported with \
+                     `no_std`",
+                ));
+            }
+        }
+
         Ok(params)
     }
 }
@@ -214,7 +309,11 @@ impl Params {
             owned_attrs,
             std_lib,
             check_mode,
+            const_validator,
+            context_validator,
             expose_inner,
+            generate,
+            rules,
             impls,
         } = self;
 
@@ -230,6 +329,9 @@ impl Params {
                 .map_or(FieldName::Unnamed, FieldName::Named),
             ty: wrapped_type.to_owned(),
         };
+        if impls.secret.is_once() {
+            add_taken_field(&mut body.fields);
+        }
 
         Ok(CodeGen {
             check_mode,
@@ -243,7 +345,11 @@ impl Params {
             ref_ty,
 
             std_lib,
+            const_validator,
+            context_validator,
             expose_inner,
+            generate,
+            rules,
             impls,
         })
     }
@@ -252,6 +358,9 @@ impl Params {
 pub struct ParamsRef {
     std_lib: StdLib,
     check_mode: IndefiniteCheckMode,
+    const_validator: bool,
+    context_validator: bool,
+    rules: Option<Rules>,
     impls: Impls,
 }
 
@@ -260,6 +369,9 @@ impl Default for ParamsRef {
         Self {
             std_lib: StdLib::default(),
             check_mode: IndefiniteCheckMode::None,
+            const_validator: false,
+            context_validator: false,
+            rules: None,
             impls: Impls::default(),
         }
     }
@@ -268,6 +380,10 @@ impl Default for ParamsRef {
 impl syn::parse::Parse for ParamsRef {
     fn parse(input: syn::parse::ParseStream) -> Result<Self, syn::Error> {
         let mut params = Self::default();
+        let mut ord_specified = false;
+        let mut serde_arg = None;
+        let mut const_validator_arg = None;
+        let mut context_validator_arg = None;
         let args =
             syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated(input)?;
 
@@ -305,13 +421,36 @@ impl syn::parse::Parse for ParamsRef {
                 syn::Meta::NameValue(nv) if nv.path == symbol::SECRET => {
                     params.impls.secret =
                         parse_lit_into_string(symbol::SECRET, parse_expr_as_lit(&nv.value)?)?
-                            .parse::<ImplOption>()
-                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))
-                            .map(DelegatingImplOption::from)?
-                            .into();
+                            .parse::<ImplSecret>()
+                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?;
                     params.impls.debug = DelegatingImplOption::Omit.into();
                     params.impls.display = DelegatingImplOption::Omit.into();
                 }
+                syn::Meta::List(list) if list.path == symbol::SECRET => {
+                    params.impls.secret = DelegatingImplOption::Implement.into();
+                    params.impls.debug = DelegatingImplOption::Omit.into();
+                    params.impls.display = DelegatingImplOption::Omit.into();
+                    parse_secret_args(&list, &mut params.impls)?;
+                    if params.impls.zeroize.is_enabled() {
+                        return Err(syn::Error::new_spanned(
+                            list,
+                            "`zeroize` is only supported on owned braids",
+                        ));
+                    }
+                    let secret = &params.impls.secret;
+                    for (span, arg) in [
+                        (secret.load, "load"),
+                        (secret.scrub, "scrub"),
+                        (secret.once, "once"),
+                    ] {
+                        if let Some(span) = span {
+                            return Err(syn::Error::new(
+                                span,
+                                format!("`{arg}` is only supported on owned braids"),
+                            ));
+                        }
+                    }
+                }
                 syn::Meta::NameValue(nv) if nv.path == symbol::ORD => {
                     params.impls.ord =
                         parse_lit_into_string(symbol::ORD, parse_expr_as_lit(&nv.value)?)?
@@ -319,16 +458,18 @@ impl syn::parse::Parse for ParamsRef {
                             .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))
                             .map(DelegatingImplOption::from)?
                             .into();
+                    ord_specified = true;
                 }
                 syn::Meta::NameValue(nv) if nv.path == symbol::SERDE => {
                     params.impls.serde =
                         parse_lit_into_string(symbol::SERDE, parse_expr_as_lit(&nv.value)?)?
-                            .parse::<ImplOption>()
-                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?
-                            .into();
+                            .parse::<ImplSerde>()
+                            .map_err(|e| syn::Error::new_spanned(&nv, e.to_owned()))?;
+                    serde_arg = Some(syn::Meta::NameValue(nv));
                 }
                 syn::Meta::Path(p) if p == symbol::SERDE => {
                     params.impls.serde = ImplOption::Implement.into();
+                    serde_arg = Some(syn::Meta::Path(p));
                 }
                 syn::Meta::Path(p) if p == symbol::VALIDATOR => {
                     params
@@ -339,6 +480,25 @@ impl syn::parse::Parse for ParamsRef {
                 syn::Meta::Path(p) if p == symbol::NO_STD => {
                     params.std_lib = StdLib::no_std(p.span());
                 }
+                syn::Meta::Path(p) if p == symbol::CONST_VALIDATOR => {
+                    params.const_validator = true;
+                    const_validator_arg = Some(p);
+                }
+                syn::Meta::Path(p) if p == symbol::CONTEXT_VALIDATOR => {
+                    params.context_validator = true;
+                    context_validator_arg = Some(p);
+                }
+                syn::Meta::List(list) if list.path == symbol::GENERATE => {
+                    return Err(syn::Error::new_spanned(
+                        list,
+                        "`generate` is only supported on owned braids",
+                    ));
+                }
+                syn::Meta::List(list) if list.path == symbol::VALIDATE => {
+                    params.rules = Some(Rules::parse(&list)?);
+                    params.const_validator = true;
+                    set_rules_validator(&mut params.check_mode, &list)?;
+                }
                 syn::Meta::Path(ref path)
                 | syn::Meta::NameValue(syn::MetaNameValue { ref path, .. }) => {
                     return Err(syn::Error::new_spanned(
@@ -355,6 +515,25 @@ impl syn::parse::Parse for ParamsRef {
             }
         }
 
+        apply_secret_defaults(&mut params.impls, ord_specified, false, serde_arg.as_ref())?;
+        check_requires_validator(
+            &params.check_mode,
+            const_validator_arg,
+            symbol::CONST_VALIDATOR,
+        )?;
+        check_requires_validator(
+            &params.check_mode,
+            context_validator_arg,
+            symbol::CONTEXT_VALIDATOR,
+        )?;
+
+        if let Some(arg) = serde_arg.filter(|_| params.impls.serde.is_sealed()) {
+            return Err(syn::Error::new_spanned(
+                arg,
+                "`serde = \"sealed\"` is only supported on owned braids",
+            ));
+        }
+
         Ok(params)
     }
 }
@@ -364,6 +543,9 @@ impl ParamsRef {
         let ParamsRef {
             std_lib,
             check_mode,
+            const_validator,
+            context_validator,
+            rules,
             impls,
         } = self;
 
@@ -388,13 +570,28 @@ impl ParamsRef {
             ident: body.ident.clone(),
             field,
             check_mode: &check_mode,
+            const_validator,
+            context_validator,
             owned_ty: None,
             std_lib: &std_lib,
             impls: &impls,
         }
         .tokens();
 
-        Ok(code_gen)
+        let rules = rules.map(|rules| {
+            rules.tokens(
+                &body.ident,
+                &body.vis,
+                &std_lib,
+                impls.secret.is_enabled(),
+                !std_lib.is_no_std(),
+            )
+        });
+
+        Ok(quote::quote! {
+            #code_gen
+            #rules
+        })
     }
 }
 
@@ -410,7 +607,11 @@ pub struct CodeGen {
     ref_ty: syn::Type,
 
     std_lib: StdLib,
+    const_validator: bool,
+    context_validator: bool,
     expose_inner: bool,
+    generate: Option<Generate>,
+    rules: Option<Rules>,
     impls: Impls,
 }
 
@@ -418,16 +619,25 @@ impl CodeGen {
     pub fn generate(&self) -> proc_macro2::TokenStream {
         let owned = self.owned().tokens();
         let ref_ = self.borrowed().tokens();
+        let rules = self.rules.as_ref().map(|rules| {
+            rules.tokens(
+                &self.body.ident,
+                &self.body.vis,
+                &self.std_lib,
+                self.impls.secret.is_enabled(),
+                true,
+            )
+        });
 
         quote::quote! {
             #owned
             #ref_
+            #rules
         }
     }
 
-    pub fn owned(&self) -> OwnedCodeGen {
+    pub fn owned(&self) -> OwnedCodeGen<'_> {
         OwnedCodeGen {
-            common_attrs: &self.body.attrs,
             check_mode: &self.check_mode,
             body: &self.body,
             field: &self.field,
@@ -436,15 +646,19 @@ impl CodeGen {
             ref_ty: &self.ref_ty,
             std_lib: &self.std_lib,
             expose_inner: self.expose_inner,
+            context_validator: self.context_validator,
+            generate: self.generate.as_ref(),
             impls: &self.impls,
         }
     }
 
-    pub fn borrowed(&self) -> RefCodeGen {
+    pub fn borrowed(&self) -> RefCodeGen<'_> {
         RefCodeGen {
             doc: &self.ref_doc,
             common_attrs: &self.body.attrs,
             check_mode: &self.check_mode,
+            const_validator: self.const_validator,
+            context_validator: self.context_validator,
             vis: &self.body.vis,
             field: self.field.clone(),
             attrs: &self.ref_attrs,
@@ -460,6 +674,268 @@ impl CodeGen {
     }
 }
 
+fn apply_secret_defaults(
+    impls: &mut Impls,
+    ord_specified: bool,
+    clone_specified: bool,
+    serde_arg: Option<&syn::Meta>,
+) -> Result<(), syn::Error> {
+    if impls.secret.is_enabled() {
+        impls.eq = ImplEq::ConstantTime;
+        if !ord_specified {
+            impls.ord = DelegatingImplOption::Omit.into();
+        }
+    }
+
+    if let Some(once) = impls.secret.once {
+        if !clone_specified {
+            impls.clone = ImplOption::Omit.into();
+        } else if impls.clone.is_enabled() {
+            return Err(syn::Error::new(
+                once,
+                "`once` secrets cannot be copied and only support `clone = \"omit\"`",
+            ));
+        }
+    }
+
+    if let Some(arg) = serde_arg {
+        if impls.secret.is_enabled() && impls.serde.is_implement() {
+            return Err(syn::Error::new_spanned(
+                arg,
+                "`secret` braids must specify how to serialize the value: `serde = \"redact\"`, \
+                 `serde = \"error\"`, `serde = \"sealed\"`, or `serde = \"expose\"`",
+            ));
+        }
+
+        if impls.secret.is_once() && (impls.serde.is_expose() || impls.serde.is_sealed()) {
+            return Err(syn::Error::new_spanned(
+                arg,
+                "`once` secrets cannot be serialized with `serde = \"expose\"` or `serde = \
+                 \"sealed\"`",
+            ));
+        }
+
+        if !impls.secret.is_enabled() && impls.serde.is_secret_mode() {
+            return Err(syn::Error::new_spanned(
+                arg,
+                "`expose`, `redact`, `error`, and `sealed` are only supported on `secret` braids",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_secret_args(list: &syn::MetaList, impls: &mut Impls) -> Result<(), syn::Error> {
+    let args = list.parse_args_with(AttrList::parse_terminated)?;
+    let mut shared = RedactionArgs::default();
+    let mut per_trait = Vec::new();
+
+    for arg in args {
+        match &arg {
+            syn::Meta::Path(p) if p == symbol::STRICT => {
+                impls.secret.set_strict();
+            }
+            syn::Meta::Path(p) if p == symbol::ZEROIZE => {
+                impls.zeroize = ImplOption::Implement.into();
+            }
+            syn::Meta::Path(p) if p == symbol::LOAD => {
+                impls.secret.load = Some(p.span());
+            }
+            syn::Meta::Path(p) if p == symbol::SCRUB => {
+                impls.secret.scrub = Some(p.span());
+            }
+            syn::Meta::Path(p) if p == symbol::ONCE => {
+                impls.secret.once = Some(p.span());
+                impls.secret.set_strict();
+            }
+            syn::Meta::NameValue(nv) if nv.path == symbol::AUDIT => {
+                let hook = parse_lit_into_type(symbol::AUDIT, parse_expr_as_lit(&nv.value)?)?;
+                impls.secret.audit = Some(hook.into_token_stream());
+            }
+            syn::Meta::List(l) if l.path == symbol::DEBUG || l.path == symbol::DISPLAY => {
+                let mut args = RedactionArgs::default();
+                for arg in l.parse_args_with(AttrList::parse_terminated)? {
+                    if !args.try_parse(&arg)? {
+                        return Err(syn::Error::new_spanned(
+                            &arg,
+                            format!(
+                                "unsupported redaction argument `{}`",
+                                arg.path().to_token_stream()
+                            ),
+                        ));
+                    }
+                }
+                per_trait.push((l.path == symbol::DEBUG, args));
+            }
+            _ if shared.try_parse(&arg)? => {}
+            syn::Meta::Path(ref path)
+            | syn::Meta::NameValue(syn::MetaNameValue { ref path, .. }) => {
+                return Err(syn::Error::new_spanned(
+                    &arg,
+                    format!("unsupported secret argument `{}`", path.to_token_stream()),
+                ));
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &arg,
+                    "unsupported secret argument".to_string(),
+                ));
+            }
+        }
+    }
+
+    shared.apply(&mut impls.secret.debug);
+    shared.apply(&mut impls.secret.display);
+    for (is_debug, args) in per_trait {
+        if is_debug {
+            args.apply(&mut impls.secret.debug);
+        } else {
+            args.apply(&mut impls.secret.display);
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Default)]
+struct RedactionArgs {
+    strategy: Option<RedactionStrategy>,
+    no_reveal: bool,
+}
+
+impl RedactionArgs {
+    /// Parses a redaction argument, returning `false` if the argument is not one
+    fn try_parse(&mut self, arg: &syn::Meta) -> Result<bool, syn::Error> {
+        let strategy = match arg {
+            syn::Meta::NameValue(nv) if nv.path == symbol::MESSAGE => RedactionStrategy::Message(
+                parse_lit_into_string(symbol::MESSAGE, parse_expr_as_lit(&nv.value)?)?,
+            ),
+            syn::Meta::NameValue(nv) if nv.path == symbol::LAST => RedactionStrategy::Last(
+                parse_lit_into_usize(symbol::LAST, parse_expr_as_lit(&nv.value)?)?,
+            ),
+            syn::Meta::Path(p) if p == symbol::LENGTH_ONLY => RedactionStrategy::LengthOnly,
+            syn::Meta::Path(p) if p == symbol::FINGERPRINT => RedactionStrategy::Fingerprint,
+            syn::Meta::Path(p) if p == symbol::NONE => {
+                self.no_reveal = true;
+                return Ok(true);
+            }
+            _ => return Ok(false),
+        };
+
+        if self.strategy.replace(strategy).is_some() {
+            return Err(syn::Error::new_spanned(
+                arg,
+                "only one of `message`, `last`, `length_only`, or `fingerprint` may be specified",
+            ));
+        }
+
+        Ok(true)
+    }
+
+    fn apply(&self, redaction: &mut Redaction) {
+        if let Some(strategy) = &self.strategy {
+            redaction.strategy = strategy.clone();
+        }
+        if self.no_reveal {
+            redaction.reveal = false;
+        }
+    }
+}
+
+pub struct Generate {
+    pub len: usize,
+    pub alphabet: syn::Ident,
+}
+
+impl Generate {
+    const ALPHABETS: &'static [(&'static str, &'static str)] = &[
+        ("base62", "BASE62"),
+        ("base64url", "BASE64URL"),
+        ("base32", "BASE32"),
+        ("hex", "HEX"),
+        ("digits", "DIGITS"),
+    ];
+
+    fn parse(list: &syn::MetaList) -> Result<Self, syn::Error> {
+        let mut len = None;
+        let mut alphabet = None;
+
+        for arg in list.parse_args_with(AttrList::parse_terminated)? {
+            match &arg {
+                syn::Meta::NameValue(nv) if nv.path == symbol::LEN => {
+                    let value = parse_lit_into_usize(symbol::LEN, parse_expr_as_lit(&nv.value)?)?;
+                    if value == 0 {
+                        return Err(syn::Error::new_spanned(
+                            &nv.value,
+                            "`len` must be greater than zero",
+                        ));
+                    }
+                    len = Some(value);
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::ALPHABET => {
+                    let name =
+                        parse_lit_into_string(symbol::ALPHABET, parse_expr_as_lit(&nv.value)?)?;
+                    let (_, constant) = Self::ALPHABETS
+                        .iter()
+                        .find(|(n, _)| *n == name)
+                        .ok_or_else(|| {
+                            syn::Error::new_spanned(
+                                &nv.value,
+                                "valid values are: `base62`, `base64url`, `base32`, `hex`, or \
+                                 `digits`",
+                            )
+                        })?;
+                    alphabet = Some(proc_macro2::Ident::new(constant, nv.value.span()));
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        &arg,
+                        format!(
+                            "unsupported generate argument `{}`",
+                            arg.path().to_token_stream()
+                        ),
+                    ));
+                }
+            }
+        }
+
+        let len = len.ok_or_else(|| {
+            syn::Error::new_spanned(list, "`generate` requires a length: `len = N`")
+        })?;
+        let alphabet = alphabet
+            .unwrap_or_else(|| proc_macro2::Ident::new("BASE62", proc_macro2::Span::call_site()));
+
+        Ok(Self { len, alphabet })
+    }
+}
+
+fn check_requires_validator(
+    check_mode: &IndefiniteCheckMode,
+    arg: Option<syn::Path>,
+    name: symbol::Symbol,
+) -> Result<(), syn::Error> {
+    match arg {
+        Some(arg) if !matches!(check_mode, IndefiniteCheckMode::Validate(_)) => Err(
+            syn::Error::new_spanned(arg, format!("`{name}` requires a `validator`")),
+        ),
+        _ => Ok(()),
+    }
+}
+
+/// Marks the braid as validated by the implementation generated from `validate(...)`
+fn set_rules_validator(
+    check_mode: &mut IndefiniteCheckMode,
+    list: &syn::MetaList,
+) -> Result<(), syn::Error> {
+    check_mode.try_set_validator(None).map_err(|_| {
+        syn::Error::new_spanned(
+            list,
+            "`validate` cannot be combined with `validator` or `normalizer`",
+        )
+    })
+}
+
 fn infer_ref_type_from_owned_name(name: &syn::Ident) -> syn::Type {
     let name_str = name.to_string();
     if name_str.ends_with("Buf") || name_str.ends_with("String") {
@@ -495,6 +971,25 @@ fn create_field_if_none(fields: &mut syn::Fields) {
     }
 }
 
+/// Adds the flag recording whether the value of a `once` secret has been taken
+fn add_taken_field(fields: &mut syn::Fields) {
+    let named = matches!(fields, syn::Fields::Named(_));
+    let field = syn::Field {
+        vis: syn::Visibility::Inherited,
+        attrs: Vec::new(),
+        colon_token: named.then(Default::default),
+        ident: named.then(|| format_ident!("__braid_taken")),
+        ty: syn::parse_quote!(bool),
+        mutability: syn::FieldMutability::None,
+    };
+
+    match fields {
+        syn::Fields::Named(fields) => fields.named.push(field),
+        syn::Fields::Unnamed(fields) => fields.unnamed.push(field),
+        syn::Fields::Unit => unreachable!("a field is always created before the flag"),
+    }
+}
+
 fn create_ref_field_if_none(fields: &mut syn::Fields) {
     if fields.is_empty() {
         let field = syn::Field {
@@ -539,8 +1034,19 @@ pub struct Field {
 }
 
 impl Field {
-    fn self_constructor(&self) -> SelfConstructorImpl {
-        SelfConstructorImpl(self)
+    fn self_constructor(&self) -> SelfConstructorImpl<'_> {
+        SelfConstructorImpl {
+            field: self,
+            once: false,
+        }
+    }
+
+    /// Constructs a `once` secret whose value has not yet been taken
+    fn once_constructor(&self) -> SelfConstructorImpl<'_> {
+        SelfConstructorImpl {
+            field: self,
+            once: true,
+        }
     }
 }
 
@@ -558,6 +1064,14 @@ impl FieldName {
         }
     }
 
+    /// The flag recording whether the value of a `once` secret has been taken
+    fn taken_flag(&self) -> proc_macro2::TokenStream {
+        match self {
+            FieldName::Named(_) => quote::quote! { __braid_taken },
+            FieldName::Unnamed => proc_macro2::Literal::u8_unsuffixed(1).into_token_stream(),
+        }
+    }
+
     fn input_name(&self) -> proc_macro2::Ident {
         match self {
             FieldName::Named(name) => name.clone(),
@@ -575,18 +1089,29 @@ impl ToTokens for FieldName {
     }
 }
 
-struct SelfConstructorImpl<'a>(&'a Field);
+struct SelfConstructorImpl<'a> {
+    field: &'a Field,
+    once: bool,
+}
 
 impl<'a> ToTokens for SelfConstructorImpl<'a> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let Self(field) = self;
+        let Self { field, once } = self;
         tokens.append(proc_macro2::Ident::new(
             "Self",
             proc_macro2::Span::call_site(),
         ));
+        let mut inner = field.name.input_name().into_token_stream();
+        if *once {
+            let taken = field.name.taken_flag();
+            inner.extend(match &field.name {
+                FieldName::Named(_) => quote::quote! { , #taken: false },
+                FieldName::Unnamed => quote::quote! { , false },
+            });
+        }
         tokens.append(proc_macro2::Group::new(
             field.name.constructor_delimiter(),
-            field.name.input_name().into_token_stream(),
+            inner,
         ));
     }
 }
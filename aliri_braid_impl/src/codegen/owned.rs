@@ -1,9 +1,8 @@
 use quote::{quote, ToTokens};
 
-use super::{impls::ToImpl, AttrList, CheckMode, Field, Impls, StdLib};
+use super::{impls::ToImpl, AttrList, CheckMode, Field, Generate, Impls, StdLib};
 
 pub struct OwnedCodeGen<'a> {
-    pub common_attrs: &'a [syn::Attribute],
     pub attrs: &'a AttrList,
     pub body: &'a syn::ItemStruct,
     pub ty: &'a syn::Ident,
@@ -12,10 +11,70 @@ pub struct OwnedCodeGen<'a> {
     pub ref_ty: &'a syn::Type,
     pub std_lib: &'a StdLib,
     pub expose_inner: bool,
+    pub context_validator: bool,
+    pub generate: Option<&'a Generate>,
     pub impls: &'a Impls,
 }
 
 impl<'a> OwnedCodeGen<'a> {
+    /// Constructs `Self` from the field's input, registering it with the scrubber if required
+    pub fn create(&self) -> proc_macro2::TokenStream {
+        let create = if self.impls.secret.is_once() {
+            self.field.once_constructor()
+        } else {
+            self.field.self_constructor()
+        };
+        if self.impls.secret.is_scrubbed() {
+            quote! { Self::scrub_registered(#create) }
+        } else {
+            create.into_token_stream()
+        }
+    }
+
+    /// Copies the value of a borrowed form into a new owned value
+    ///
+    /// `once` secrets are built directly, as their borrowed form does not implement `ToOwned`.
+    fn copy_from_ref(&self, r: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        if self.impls.secret.is_once() {
+            let field = &self.field.name;
+            let param = field.input_name();
+            let create = self.create();
+            let core = self.std_lib.core();
+            quote! {{
+                let #param = ::#core::convert::From::from(&#r.#field);
+                #create
+            }}
+        } else {
+            let alloc = self.std_lib.alloc();
+            quote! { ::#alloc::borrow::ToOwned::to_owned(#r) }
+        }
+    }
+
+    /// Converts each error reported by `validate_all()` into the braid's error type
+    fn map_errors(&self, validator: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let core = self.std_lib.core();
+        let alloc = self.std_lib.alloc();
+        let error = self.impls.secret.error_type(validator);
+        let convert_err = self.impls.secret.convert_error(validator, self.ty, core);
+        quote! {
+            .map_err(|errors| {
+                ::#core::iter::Iterator::collect::<::#alloc::vec::Vec<#error>>(::#core::iter::Iterator::map(
+                    ::#core::iter::IntoIterator::into_iter(errors),
+                    #convert_err,
+                ))
+            })
+        }
+    }
+
+    /// Whether the owned type implements `Drop`, preventing the field from being moved out
+    fn has_drop(&self) -> bool {
+        self.impls.zeroize.is_enabled() || self.impls.secret.is_scrubbed()
+    }
+
+    fn constness(&self) -> Option<proc_macro2::TokenStream> {
+        (!self.impls.secret.is_scrubbed()).then(|| quote! { const })
+    }
+
     fn constructor(&self) -> proc_macro2::TokenStream {
         match &self.check_mode {
             CheckMode::None => self.infallible_constructor(),
@@ -29,10 +88,11 @@ impl<'a> OwnedCodeGen<'a> {
         let static_doc_comment = format!("{doc_comment} from a static reference");
 
         let param = self.field.name.input_name();
-        let create = self.field.self_constructor();
+        let create = self.create();
+        let constness = self.constness();
         let ref_ty = self.ref_ty;
         let field_ty = &self.field.ty;
-        let alloc = self.std_lib.alloc();
+        let from_static = self.copy_from_ref(quote! { #ref_ty::from_static(raw) });
 
         let vis = self
             .expose_inner
@@ -41,7 +101,7 @@ impl<'a> OwnedCodeGen<'a> {
         quote! {
             #[doc = #doc_comment]
             #[inline]
-            #vis const fn new(#param: #field_ty) -> Self {
+            #vis #constness fn new(#param: #field_ty) -> Self {
                 #create
             }
 
@@ -49,7 +109,7 @@ impl<'a> OwnedCodeGen<'a> {
             #[doc = #static_doc_comment]
             #[track_caller]
             pub fn from_static(raw: &'static str) -> Self {
-                ::#alloc::borrow::ToOwned::to_owned(#ref_ty::from_static(raw))
+                #from_static
             }
         }
     }
@@ -66,6 +126,12 @@ impl<'a> OwnedCodeGen<'a> {
             self.ty, validator_tokens
         );
 
+        let collecting_doc_comment = format!(
+            "Constructs a new {} if it conforms to [`{}`], reporting every rule that the value \
+             breaks rather than only the first",
+            self.ty, validator_tokens
+        );
+
         let doc_comment_unsafe = format!(
             "Constructs a new {} without validation\n\n# Safety\n\nConsumers of this function \
              must ensure that values conform to [`{}`]. Failure to maintain this invariant may \
@@ -75,28 +141,68 @@ impl<'a> OwnedCodeGen<'a> {
 
         let validator = crate::as_validator(validator);
         let param = self.field.name.input_name();
-        let create = self.field.self_constructor();
+        let create = self.create();
+        let constness = self.constness();
         let ref_ty = self.ref_ty;
         let field_ty = &self.field.ty;
         let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
+        let from_static = self.copy_from_ref(quote! { #ref_ty::from_static(raw) });
 
         let vis = self
             .expose_inner
             .then(|| proc_macro2::Ident::new("pub", proc_macro2::Span::call_site()));
 
+        let error = self.impls.secret.error_type(&validator);
+        let map_err = self.impls.secret.map_error(&validator, self.ty, core);
+        let map_errs = self.map_errors(&validator);
+
+        let (body, collecting_body) = if self.impls.zeroize.is_enabled() {
+            let field_name = &self.field.name;
+            (
+                quote! {
+                    let value = #create;
+                    #validator::validate(value.#field_name.as_ref())#map_err?;
+                    ::#core::result::Result::Ok(value)
+                },
+                quote! {
+                    let value = #create;
+                    #validator::validate_all(value.#field_name.as_ref())#map_errs?;
+                    ::#core::result::Result::Ok(value)
+                },
+            )
+        } else {
+            (
+                quote! {
+                    #validator::validate(#param.as_ref())#map_err?;
+                    ::#core::result::Result::Ok(#create)
+                },
+                quote! {
+                    #validator::validate_all(#param.as_ref())#map_errs?;
+                    ::#core::result::Result::Ok(#create)
+                },
+            )
+        };
+
         quote! {
             #[doc = #doc_comment]
             #[inline]
-            #vis fn new(#param: #field_ty) -> ::#core::result::Result<Self, #validator::Error> {
-                #validator::validate(#param.as_ref())?;
-                ::#core::result::Result::Ok(#create)
+            #vis fn new(#param: #field_ty) -> ::#core::result::Result<Self, #error> {
+                #body
+            }
+
+            #[doc = #collecting_doc_comment]
+            #[inline]
+            #vis fn try_new_collecting(
+                #param: #field_ty,
+            ) -> ::#core::result::Result<Self, ::#alloc::vec::Vec<#error>> {
+                #collecting_body
             }
 
             #[doc = #doc_comment_unsafe]
             #[allow(unsafe_code)]
             #[inline]
-            #vis const unsafe fn new_unchecked(#param: #field_ty) -> Self {
+            #vis #constness unsafe fn new_unchecked(#param: #field_ty) -> Self {
                 #create
             }
 
@@ -108,11 +214,54 @@ impl<'a> OwnedCodeGen<'a> {
             #[doc = "This function will panic if the provided raw string is not valid."]
             #[track_caller]
             pub fn from_static(raw: &'static str) -> Self {
-                ::#alloc::borrow::ToOwned::to_owned(#ref_ty::from_static(raw))
+                #from_static
             }
         }
     }
 
+    fn make_context_constructor(&self) -> Option<proc_macro2::TokenStream> {
+        let CheckMode::Validate(validator) = &self.check_mode else {
+            return None;
+        };
+        if !self.context_validator {
+            return None;
+        }
+
+        let doc_comment = format!(
+            "Constructs a new {} if it conforms to [`{}`], including the rules that depend on the \
+             given context",
+            self.ty,
+            validator.to_token_stream(),
+        );
+
+        let context_validator = crate::as_context_validator(validator);
+        let validator = crate::as_validator(validator);
+        let param = self.field.name.input_name();
+        let field_name = &self.field.name;
+        let field_ty = &self.field.ty;
+        let core = self.std_lib.core();
+
+        let vis = self
+            .expose_inner
+            .then(|| proc_macro2::Ident::new("pub", proc_macro2::Span::call_site()));
+
+        let error = self.impls.secret.error_type(&validator);
+        let map_err = self.impls.secret.map_error(&validator, self.ty, core);
+
+        Some(quote! {
+            #[doc = #doc_comment]
+            #[inline]
+            #vis fn new_with(
+                context: &#context_validator::Context,
+                #param: #field_ty,
+            ) -> ::#core::result::Result<Self, #error> {
+                let value = Self::new(#param)?;
+                #context_validator::validate_with(context, value.#field_name.as_ref())#map_err?;
+                ::#core::result::Result::Ok(value)
+            }
+        })
+    }
+
     fn normalized_constructor(&self, normalizer: &syn::Type) -> proc_macro2::TokenStream {
         let normalizer_tokens = normalizer.to_token_stream();
         let doc_comment = format!(
@@ -126,6 +275,12 @@ impl<'a> OwnedCodeGen<'a> {
             self.ty, normalizer_tokens
         );
 
+        let collecting_doc_comment = format!(
+            "Normalizes the input and constructs a new {} if the result conforms to [`{}`], \
+             reporting every rule that it breaks rather than only the first",
+            self.ty, normalizer_tokens
+        );
+
         let doc_comment_unsafe = format!(
             "Constructs a new {} without validation or normalization\n\n# Safety\n\nConsumers of \
              this function must ensure that values conform to [`{}`] and are in normalized form. \
@@ -137,27 +292,90 @@ impl<'a> OwnedCodeGen<'a> {
         let validator = crate::as_validator(normalizer);
         let normalizer = crate::as_normalizer(normalizer);
         let param = self.field.name.input_name();
-        let create = self.field.self_constructor();
+        let create = self.create();
+        let constness = self.constness();
         let ref_ty = self.ref_ty;
         let field_ty = &self.field.ty;
         let core = self.std_lib.core();
+        let alloc = self.std_lib.alloc();
 
         let vis = self
             .expose_inner
             .then(|| proc_macro2::Ident::new("pub", proc_macro2::Span::call_site()));
 
+        let error = self.impls.secret.error_type(&validator);
+        let map_err = self.impls.secret.map_error(&validator, self.ty, core);
+        let map_errs = self.map_errors(&validator);
+
+        // The validator checks the normalized value, so every rule it breaks is collected after
+        // normalizing, while a failure of the normalizer itself is reported on its own
+        let collecting_map_err = quote! {
+            #map_err.map_err(|e| ::#alloc::vec::Vec::from([e]))
+        };
+
+        let (body, collecting_body) = if self.impls.zeroize.is_enabled() {
+            let field_name = &self.field.name;
+            let normalize = |map_err: &proc_macro2::TokenStream| {
+                quote! {
+                    let value = #create;
+                    let raw = ::#core::convert::AsRef::<str>::as_ref(&value.#field_name);
+                    let value = match #normalizer::normalize(raw)#map_err? {
+                        // A borrow of the whole value means it is already normalized, but a
+                        // sub-slice must be copied out so that `value` is wiped when dropped
+                        ::#alloc::borrow::Cow::Borrowed(normalized) if ::#core::ptr::eq(normalized, raw) => value,
+                        normalized => {
+                            let #param = ::#core::convert::From::from(normalized);
+                            #create
+                        }
+                    };
+                }
+            };
+            let (normalize, collecting_normalize) =
+                (normalize(&map_err), normalize(&collecting_map_err));
+            (
+                quote! {
+                    #normalize
+                    ::#core::result::Result::Ok(value)
+                },
+                quote! {
+                    #collecting_normalize
+                    #validator::validate_all(::#core::convert::AsRef::<str>::as_ref(&value.#field_name))#map_errs?;
+                    ::#core::result::Result::Ok(value)
+                },
+            )
+        } else {
+            (
+                quote! {
+                    let #param = ::#core::convert::From::from(#normalizer::normalize(#param.as_ref())#map_err?);
+                    ::#core::result::Result::Ok(#create)
+                },
+                quote! {
+                    let #param = ::#core::convert::From::from(#normalizer::normalize(#param.as_ref())#collecting_map_err?);
+                    #validator::validate_all(::#core::convert::AsRef::<str>::as_ref(&#param))#map_errs?;
+                    ::#core::result::Result::Ok(#create)
+                },
+            )
+        };
+
         quote! {
             #[doc = #doc_comment]
             #[inline]
-            #vis fn new(#param: #field_ty) -> ::#core::result::Result<Self, #validator::Error> {
-                let #param = ::#core::convert::From::from(#normalizer::normalize(#param.as_ref())?);
-                ::#core::result::Result::Ok(#create)
+            #vis fn new(#param: #field_ty) -> ::#core::result::Result<Self, #error> {
+                #body
+            }
+
+            #[doc = #collecting_doc_comment]
+            #[inline]
+            #vis fn try_new_collecting(
+                #param: #field_ty,
+            ) -> ::#core::result::Result<Self, ::#alloc::vec::Vec<#error>> {
+                #collecting_body
             }
 
             #[doc = #doc_comment_unsafe]
             #[allow(unsafe_code)]
             #[inline]
-            #vis const unsafe fn new_unchecked(#param: #field_ty) -> Self {
+            #vis #constness unsafe fn new_unchecked(#param: #field_ty) -> Self {
                 #create
             }
 
@@ -183,7 +401,18 @@ impl<'a> OwnedCodeGen<'a> {
 
         let ref_type = self.ref_ty;
         let field = &self.field.name;
+        let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
+        let box_str = if self.has_drop() {
+            quote! {
+                let box_str: ::#alloc::boxed::Box<str> =
+                    ::#core::convert::From::from(::#core::convert::AsRef::<str>::as_ref(&self.#field));
+            }
+        } else {
+            quote! {
+                let box_str = ::#alloc::string::String::from(self.#field).into_boxed_str();
+            }
+        };
         let box_pointer_reinterpret_safety_comment = {
             let doc = format!(
                 "SAFETY: `{ty}` is `#[repr(transparent)]` around a single `str` field, so a `*mut \
@@ -203,7 +432,7 @@ impl<'a> OwnedCodeGen<'a> {
             #[inline]
             pub fn into_boxed_ref(self) -> ::#alloc::boxed::Box<#ref_type> {
                 #box_pointer_reinterpret_safety_comment
-                let box_str = ::#alloc::string::String::from(self.#field).into_boxed_str();
+                #box_str
                 unsafe { ::#alloc::boxed::Box::from_raw(::#alloc::boxed::Box::into_raw(box_str) as *mut #ref_type) }
             }
         }
@@ -220,55 +449,434 @@ impl<'a> OwnedCodeGen<'a> {
         let vis = self
             .expose_inner
             .then(|| proc_macro2::Ident::new("pub", proc_macro2::Span::call_site()));
+        let audit = self.impls.secret.audit(&self.ty, "Take");
+
+        if self.has_drop() {
+            let core = self.std_lib.core();
+            let unregister = self
+                .impls
+                .secret
+                .scrub_unregister(&quote! { ::#core::convert::AsRef::<str>::as_ref(&self.#field) });
+            let take_safety_comment = quote! {
+                #[doc = "SAFETY: `self` is wrapped in `ManuallyDrop`, so the field is read out \
+                         exactly once and is never wiped or dropped by `self`"]
+                fn take_safety_comment() {}
+            };
 
-        quote! {
+            quote! {
+                #[doc = #doc]
+                #[allow(unsafe_code)]
+                #[inline]
+                #vis fn take(self) -> #field_ty {
+                    #audit
+                    #unregister
+                    #take_safety_comment
+                    let this = ::#core::mem::ManuallyDrop::new(self);
+                    unsafe { ::#core::ptr::read(&this.#field) }
+                }
+            }
+        } else {
+            quote! {
+                #[doc = #doc]
+                #[inline]
+                #vis fn take(self) -> #field_ty {
+                    #audit
+                    self.#field
+                }
+            }
+        }
+    }
+
+    fn make_edit(&self) -> Option<proc_macro2::TokenStream> {
+        let validator = match &self.check_mode {
+            CheckMode::None => return None,
+            CheckMode::Validate(validator) | CheckMode::Normalize(validator) => validator,
+        };
+        if self.impls.secret.is_once() {
+            return None;
+        }
+
+        let check = if matches!(self.check_mode, CheckMode::Normalize(_)) {
+            "renormalized"
+        } else if self.context_validator {
+            "revalidated against the given context"
+        } else {
+            "revalidated"
+        };
+        let doc = format!(
+            "Modifies the underlying value in place\n\nThe closure edits a copy of the value, \
+             which is then {check} with [`{}`]. If the copy is rejected, this `{}` keeps its \
+             previous value.\n\n# Errors\n\nReturns an error if the edited value is rejected.",
+            validator.to_token_stream(),
+            self.ty,
+        );
+
+        let context_validator = self
+            .context_validator
+            .then(|| crate::as_context_validator(validator));
+        let validator = crate::as_validator(validator);
+        let error = self.impls.secret.error_type(&validator);
+        let field = &self.field.name;
+        let param = self.field.name.input_name();
+        let field_ty = &self.field.ty;
+        let core = self.std_lib.core();
+        let audit = self.impls.secret.audit(&self.ty, "Edit");
+
+        let vis = self
+            .expose_inner
+            .then(|| proc_macro2::Ident::new("pub", proc_macro2::Span::call_site()));
+
+        // Edits of values with context-dependent rules must be checked against a context too
+        let (name, context, revalidate) = if let Some(context_validator) = context_validator {
+            (
+                quote! { edit_with },
+                Some(quote! { context: &#context_validator::Context, }),
+                quote! { Self::new_with(context, #param)? },
+            )
+        } else {
+            (quote! { edit }, None, quote! { Self::new(#param)? })
+        };
+
+        let copy = quote! {
+            <#field_ty as ::#core::convert::From<&str>>::from(
+                ::#core::convert::AsRef::<str>::as_ref(&self.#field),
+            )
+        };
+        let edit = if self.impls.zeroize.is_enabled() {
+            // The copy is wiped even if the closure panics
+            quote! {
+                let mut copy = ::aliri_braid::zeroize::Zeroizing::new(#copy);
+                f(&mut copy);
+                let #param = ::#core::mem::replace(
+                    &mut *copy,
+                    <#field_ty as ::#core::convert::From<&str>>::from(""),
+                );
+            }
+        } else {
+            quote! {
+                let mut #param = #copy;
+                f(&mut #param);
+            }
+        };
+
+        Some(quote! {
             #[doc = #doc]
             #[inline]
-            #vis fn take(self) -> #field_ty {
-                self.#field
+            #vis fn #name<F>(&mut self, #context f: F) -> ::#core::result::Result<(), #error>
+            where
+                F: ::#core::ops::FnOnce(&mut #field_ty),
+            {
+                #audit
+                #edit
+                *self = #revalidate;
+                ::#core::result::Result::Ok(())
             }
+        })
+    }
+
+    fn make_duplicate(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.impls.clone.is_explicit() {
+            return None;
         }
+
+        let doc = format!(
+            "Creates a copy of this `{}`\n\n`Clone` is deliberately not implemented for this \
+             type, so that each copy of the value is made explicitly.",
+            self.ty
+        );
+        let field = &self.field.name;
+        let param = self.field.name.input_name();
+        let create = self.create();
+        let core = self.std_lib.core();
+        let audit = self.impls.secret.audit(&self.ty, "Duplicate");
+
+        Some(quote! {
+            #[doc = #doc]
+            #[inline]
+            #[must_use]
+            pub fn duplicate(&self) -> Self {
+                #audit
+                let #param = ::#core::clone::Clone::clone(&self.#field);
+                #create
+            }
+        })
+    }
+
+    fn make_take_once(&self) -> Option<proc_macro2::TokenStream> {
+        self.impls.secret.once?;
+
+        let ty_name = self.ty.to_string();
+        let field = &self.field.name;
+        let field_ty = &self.field.ty;
+        let core = self.std_lib.core();
+        let doc = format!(
+            "Takes the underlying [`{}`] value, leaving this `{ty_name}` empty\n\nThe value is \
+             moved out, so no copy of it remains in this `{ty_name}`, and any later attempt to \
+             take it again fails.\n\n# Errors\n\nReturns an error if the value has already been \
+             taken.",
+            field_ty.to_token_stream()
+        );
+        let taken_doc = format!("Returns whether the value of this `{ty_name}` has been taken");
+
+        let vis = self
+            .expose_inner
+            .then(|| proc_macro2::Ident::new("pub", proc_macro2::Span::call_site()));
+        let taken = field.taken_flag();
+        let value = quote! { ::#core::convert::AsRef::<str>::as_ref(&self.#field) };
+        let audit = self.impls.secret.audit(&self.ty, "Take");
+        let unregister = self.impls.secret.scrub_unregister(&value);
+
+        Some(quote! {
+            #[doc = #doc]
+            #[inline]
+            #vis fn take_once(&mut self) -> ::#core::result::Result<#field_ty, ::aliri_braid::AlreadyTaken> {
+                if self.is_taken() {
+                    return ::#core::result::Result::Err(::aliri_braid::AlreadyTaken::new(#ty_name));
+                }
+
+                #audit
+                #unregister
+                self.#taken = true;
+                ::#core::result::Result::Ok(::#core::mem::replace(
+                    &mut self.#field,
+                    ::#core::convert::From::from(""),
+                ))
+            }
+
+            #[doc = #taken_doc]
+            #[inline]
+            pub fn is_taken(&self) -> bool {
+                self.#taken
+            }
+        })
+    }
+
+    fn make_loaders(&self) -> Option<proc_macro2::TokenStream> {
+        self.impls.secret.load?;
+
+        let ty_name = self.ty.to_string();
+        let env_doc = format!(
+            "Loads a `{ty_name}` from the environment variable `var`\n\nThe value is checked as \
+             if by [`FromStr`](::std::str::FromStr), and is never included in the error."
+        );
+        let file_doc = format!(
+            "Loads a `{ty_name}` from the file at `path`\n\nA single trailing line ending is \
+             removed, and the value is checked as if by [`FromStr`](::std::str::FromStr). The \
+             value is never included in the error."
+        );
+
+        Some(quote! {
+            #[doc = #env_doc]
+            pub fn from_env(
+                var: &str,
+            ) -> ::std::result::Result<Self, ::aliri_braid::load::LoadError<<Self as ::std::str::FromStr>::Err>> {
+                ::aliri_braid::load::from_env(#ty_name, var, <Self as ::std::str::FromStr>::from_str)
+            }
+
+            #[doc = #file_doc]
+            pub fn from_file<P: ::std::convert::AsRef<::std::path::Path>>(
+                path: P,
+            ) -> ::std::result::Result<Self, ::aliri_braid::load::LoadError<<Self as ::std::str::FromStr>::Err>> {
+                ::aliri_braid::load::from_file(
+                    #ty_name,
+                    ::std::convert::AsRef::as_ref(&path),
+                    <Self as ::std::str::FromStr>::from_str,
+                )
+            }
+        })
+    }
+
+    fn make_generators(&self) -> Option<proc_macro2::TokenStream> {
+        let generate = self.generate?;
+        let len = generate.len;
+        let alphabet = &generate.alphabet;
+        let core = self.std_lib.core();
+
+        let alphabet_doc = alphabet.to_string().to_lowercase();
+        let doc = format!(
+            "Generates a random `{}` of {len} characters drawn from the {alphabet_doc} alphabet",
+            self.ty
+        );
+        let os_doc = "Uses the operating system's random number generator.";
+        let rng_doc = "Uses the provided random number generator, which can be seeded to make \
+                       generation deterministic in tests.";
+
+        let (result, generate_with) = match &self.check_mode {
+            CheckMode::None => (
+                quote! { Self },
+                quote! {
+                    Self::new(::#core::convert::From::from(
+                        ::aliri_braid::generate::generate_with(rng, #len, ::aliri_braid::generate::Alphabet::#alphabet),
+                    ))
+                },
+            ),
+            CheckMode::Validate(validator) | CheckMode::Normalize(validator) => {
+                let error = self
+                    .impls
+                    .secret
+                    .error_type(&crate::as_validator(validator));
+                (
+                    quote! { ::#core::result::Result<Self, #error> },
+                    quote! {
+                        ::aliri_braid::generate::generate_valid_with(
+                            rng,
+                            #len,
+                            ::aliri_braid::generate::Alphabet::#alphabet,
+                            |raw| Self::new(::#core::convert::From::from(raw)),
+                        )
+                    },
+                )
+            }
+        };
+
+        let errors_doc = (!matches!(self.check_mode, CheckMode::None)).then(|| {
+            quote! {
+                #[doc = ""]
+                #[doc = "# Errors"]
+                #[doc = ""]
+                #[doc = "Values that are rejected by the validator are discarded and generated again. An error is returned if too many values in a row are rejected."]
+            }
+        });
+
+        Some(quote! {
+            #[doc = #doc]
+            #[doc = ""]
+            #[doc = #os_doc]
+            #errors_doc
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = ""]
+            #[doc = "This function will panic if the operating system fails to provide random data."]
+            #[inline]
+            pub fn generate() -> #result {
+                Self::generate_with(&mut ::aliri_braid::generate::os_rng())
+            }
+
+            #[doc = #doc]
+            #[doc = ""]
+            #[doc = #rng_doc]
+            #errors_doc
+            pub fn generate_with<R>(rng: &mut R) -> #result
+            where
+                R: ::aliri_braid::generate::rand_core::CryptoRngCore + ?::#core::marker::Sized,
+            {
+                #generate_with
+            }
+        })
+    }
+
+    fn make_scrub_registered(&self) -> Option<proc_macro2::TokenStream> {
+        let field = &self.field.name;
+        self.impls.secret.is_scrubbed().then(|| {
+            // The replacement must not depend on the runtime policy, which may reveal the
+            // value, nor be reported to the audit hook, so it bypasses `Display`
+            quote! {
+                #[inline]
+                fn scrub_registered(self) -> Self {
+                    let replacement = ::std::string::ToString::to_string(
+                        &::aliri_braid::redaction::StaticallyRedacted(&self),
+                    );
+                    ::aliri_braid::Scrubber::global().insert(
+                        ::std::convert::AsRef::<str>::as_ref(&self.#field),
+                        &replacement,
+                    );
+                    self
+                }
+            }
+        })
     }
 
     fn inherent(&self) -> proc_macro2::TokenStream {
         let name = self.ty;
         let constructor = self.constructor();
+        let context_constructor = self.make_context_constructor();
         let into_boxed_ref = self.make_into_boxed_ref();
-        let into_string = self.make_take();
+        let into_string = (!self.impls.secret.is_strict()).then(|| self.make_take());
+        let edit = self.make_edit();
+        let duplicate = self.make_duplicate();
+        let take_once = self.make_take_once();
+        let loaders = self.make_loaders();
+        let generators = self.make_generators();
+        let scrub_registered = self.make_scrub_registered();
 
         quote! {
             #[automatically_derived]
             impl #name {
                 #constructor
+                #context_constructor
                 #into_boxed_ref
                 #into_string
+                #edit
+                #duplicate
+                #take_once
+                #loaders
+                #generators
+                #scrub_registered
             }
         }
     }
 
     fn common_conversion(&self) -> proc_macro2::TokenStream {
         let ty = self.ty;
-        let field_name = &self.field.name;
         let ref_ty = self.ref_ty;
         let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
 
-        quote! {
-            #[automatically_derived]
-            impl ::#core::convert::From<&'_ #ref_ty> for #ty {
-                #[inline]
-                fn from(s: &#ref_ty) -> Self {
-                    ::#alloc::borrow::ToOwned::to_owned(s)
+        let str_conversion = (!self.impls.secret.is_strict()).then(|| {
+            quote! {
+                #[automatically_derived]
+                impl ::#core::convert::From<#ty> for ::#alloc::string::String {
+                    #[inline]
+                    fn from(s: #ty) -> Self {
+                        ::#core::convert::From::from(s.take())
+                    }
+                }
+
+                #[automatically_derived]
+                impl ::#core::convert::AsRef<str> for #ty {
+                    #[inline]
+                    fn as_ref(&self) -> &str {
+                        self.as_str()
+                    }
                 }
             }
+        });
 
-            #[automatically_derived]
-            impl ::#core::convert::From<#ty> for ::#alloc::string::String {
-                #[inline]
-                fn from(s: #ty) -> Self {
-                    ::#core::convert::From::from(s.#field_name)
+        // The value of a `once` secret must not be copied out of its borrowed form
+        let copy_conversion = (!self.impls.secret.is_once()).then(|| {
+            quote! {
+                #[automatically_derived]
+                impl ::#core::convert::From<&'_ #ref_ty> for #ty {
+                    #[inline]
+                    fn from(s: &#ref_ty) -> Self {
+                        ::#alloc::borrow::ToOwned::to_owned(s)
+                    }
+                }
+
+                #[automatically_derived]
+                impl<'a> ::#core::convert::From<::#alloc::borrow::Cow<'a, #ref_ty>> for #ty {
+                    #[inline]
+                    fn from(r: ::#alloc::borrow::Cow<'a, #ref_ty>) -> Self {
+                        match r {
+                            ::#alloc::borrow::Cow::Borrowed(b) => ::#alloc::borrow::ToOwned::to_owned(b),
+                            ::#alloc::borrow::Cow::Owned(o) => o,
+                        }
+                    }
+                }
+
+                #[automatically_derived]
+                impl<'a> ::#core::convert::From<#ty> for ::#alloc::borrow::Cow<'a, #ref_ty> {
+                    #[inline]
+                    fn from(owned: #ty) -> Self {
+                        ::#alloc::borrow::Cow::Owned(owned)
+                    }
                 }
             }
+        });
+
+        quote! {
+            #copy_conversion
+            #str_conversion
 
             #[automatically_derived]
             impl ::#core::borrow::Borrow<#ref_ty> for #ty {
@@ -286,15 +894,6 @@ impl<'a> OwnedCodeGen<'a> {
                 }
             }
 
-            #[automatically_derived]
-            impl ::#core::convert::AsRef<str> for #ty {
-                #[inline]
-                fn as_ref(&self) -> &str {
-                    self.as_str()
-                }
-            }
-
-
             #[automatically_derived]
             impl ::#core::convert::From<#ty> for ::#alloc::boxed::Box<#ref_ty> {
                 #[inline]
@@ -310,25 +909,6 @@ impl<'a> OwnedCodeGen<'a> {
                     r.into_owned()
                 }
             }
-
-            #[automatically_derived]
-            impl<'a> ::#core::convert::From<::#alloc::borrow::Cow<'a, #ref_ty>> for #ty {
-                #[inline]
-                fn from(r: ::#alloc::borrow::Cow<'a, #ref_ty>) -> Self {
-                    match r {
-                        ::#alloc::borrow::Cow::Borrowed(b) => ::#alloc::borrow::ToOwned::to_owned(b),
-                        ::#alloc::borrow::Cow::Owned(o) => o,
-                    }
-                }
-            }
-
-            #[automatically_derived]
-            impl<'a> ::#core::convert::From<#ty> for ::#alloc::borrow::Cow<'a, #ref_ty> {
-                #[inline]
-                fn from(owned: #ty) -> Self {
-                    ::#alloc::borrow::Cow::Owned(owned)
-                }
-            }
         }
     }
 
@@ -338,6 +918,7 @@ impl<'a> OwnedCodeGen<'a> {
         let field_name = &self.field.name;
         let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
+        let borrow_str = self.borrow_str();
 
         quote! {
             #[automatically_derived]
@@ -374,13 +955,7 @@ impl<'a> OwnedCodeGen<'a> {
                 }
             }
 
-            #[automatically_derived]
-            impl ::#core::borrow::Borrow<str> for #ty {
-                #[inline]
-                fn borrow(&self) -> &str {
-                    self.as_str()
-                }
-            }
+            #borrow_str
 
             #[automatically_derived]
             impl ::#core::ops::Deref for #ty {
@@ -394,6 +969,23 @@ impl<'a> OwnedCodeGen<'a> {
         }
     }
 
+    fn borrow_str(&self) -> Option<proc_macro2::TokenStream> {
+        let ty = self.ty;
+        let core = self.std_lib.core();
+
+        (!self.impls.secret.is_strict()).then(|| {
+            quote! {
+                #[automatically_derived]
+                impl ::#core::borrow::Borrow<str> for #ty {
+                    #[inline]
+                    fn borrow(&self) -> &str {
+                        self.as_str()
+                    }
+                }
+            }
+        })
+    }
+
     fn unchecked_safety_comment(is_normalized: bool) -> proc_macro2::TokenStream {
         let doc = format!(
             "SAFETY: The value was satisfies the type's invariant and conforms to the required \
@@ -419,51 +1011,49 @@ impl<'a> OwnedCodeGen<'a> {
         let validator = crate::as_validator(validator);
         let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
+        let from_ref = self.copy_from_ref(quote! { ref_ty });
+        let borrow_str = self.borrow_str();
         let unchecked_safety_comment = Self::unchecked_safety_comment(false);
+        let error = self.impls.secret.error_type(&validator);
+        let map_err = self.impls.secret.map_error(&validator, ty, core);
 
         quote! {
             #[automatically_derived]
             impl ::#core::convert::TryFrom<::#alloc::string::String> for #ty {
-                type Error = #validator::Error;
+                type Error = #error;
 
                 #[inline]
                 fn try_from(s: ::#alloc::string::String) -> ::#core::result::Result<Self, Self::Error> {
                     const fn ensure_try_from_string_error_converts_to_validator_error<T: ?Sized + From<<#field_ty as ::#core::convert::TryFrom<::#alloc::string::String>>::Error>>() {}
-                    ensure_try_from_string_error_converts_to_validator_error::<Self::Error>();
+                    ensure_try_from_string_error_converts_to_validator_error::<#validator::Error>();
 
-                    Self::new(::#core::convert::TryFrom::try_from(s)?)
+                    Self::new(::#core::convert::TryFrom::try_from(s)#map_err?)
                 }
             }
 
             #[automatically_derived]
             impl ::#core::convert::TryFrom<&'_ str> for #ty {
-                type Error = #validator::Error;
+                type Error = #error;
 
                 #[inline]
                 fn try_from(s: &str) -> ::#core::result::Result<Self, Self::Error> {
                     let ref_ty = #ref_ty::from_str(s)?;
-                    ::#core::result::Result::Ok(::#alloc::borrow::ToOwned::to_owned(ref_ty))
+                    ::#core::result::Result::Ok(#from_ref)
                 }
             }
 
             #[automatically_derived]
             impl ::#core::str::FromStr for #ty {
-                type Err = #validator::Error;
+                type Err = #error;
 
                 #[inline]
                 fn from_str(s: &str) -> ::#core::result::Result<Self, Self::Err> {
                     let ref_ty = #ref_ty::from_str(s)?;
-                    ::#core::result::Result::Ok(::#alloc::borrow::ToOwned::to_owned(ref_ty))
+                    ::#core::result::Result::Ok(#from_ref)
                 }
             }
 
-            #[automatically_derived]
-            impl ::#core::borrow::Borrow<str> for #ty {
-                #[inline]
-                fn borrow(&self) -> &str {
-                    self.as_str()
-                }
-            }
+            #borrow_str
 
             #[automatically_derived]
             impl ::#core::ops::Deref for #ty {
@@ -488,24 +1078,26 @@ impl<'a> OwnedCodeGen<'a> {
         let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
         let unchecked_safety_comment = Self::unchecked_safety_comment(true);
+        let error = self.impls.secret.error_type(&validator);
+        let map_err = self.impls.secret.map_error(&validator, ty, core);
 
         quote! {
             #[automatically_derived]
             impl ::#core::convert::TryFrom<::#alloc::string::String> for #ty {
-                type Error = #validator::Error;
+                type Error = #error;
 
                 #[inline]
                 fn try_from(s: ::#alloc::string::String) -> ::#core::result::Result<Self, Self::Error> {
                     const fn ensure_try_from_string_error_converts_to_validator_error<T: ?Sized + From<<#field_ty as ::#core::convert::TryFrom<::#alloc::string::String>>::Error>>() {}
-                    ensure_try_from_string_error_converts_to_validator_error::<Self::Error>();
+                    ensure_try_from_string_error_converts_to_validator_error::<#validator::Error>();
 
-                    Self::new(::#core::convert::TryFrom::try_from(s)?)
+                    Self::new(::#core::convert::TryFrom::try_from(s)#map_err?)
                 }
             }
 
             #[automatically_derived]
             impl ::#core::convert::TryFrom<&'_ str> for #ty {
-                type Error = #validator::Error;
+                type Error = #error;
 
                 #[inline]
                 fn try_from(s: &str) -> ::#core::result::Result<Self, Self::Error> {
@@ -516,7 +1108,7 @@ impl<'a> OwnedCodeGen<'a> {
 
             #[automatically_derived]
             impl ::#core::str::FromStr for #ty {
-                type Err = #validator::Error;
+                type Err = #error;
 
                 #[inline]
                 fn from_str(s: &str) -> ::#core::result::Result<Self, Self::Err> {
@@ -554,9 +1146,11 @@ impl<'a> OwnedCodeGen<'a> {
     }
 
     pub fn tokens(&self) -> proc_macro2::TokenStream {
+        let eq = self.impls.eq.to_owned_impl(self);
         let clone = self.impls.clone.to_owned_impl(self);
         let display = self.impls.display.to_owned_impl(self);
         let secret = self.impls.secret.to_owned_impl(self);
+        let zeroize = self.impls.zeroize.to_owned_impl(self);
         let debug = self.impls.debug.to_owned_impl(self);
         let ord = self.impls.ord.to_owned_impl(self);
         let serde = self.impls.serde.to_owned_impl(self);
@@ -567,10 +1161,13 @@ impl<'a> OwnedCodeGen<'a> {
         let inherent = self.inherent();
         let conversion = self.conversion();
 
+        // `once` secrets also hold a flag recording whether their value has been taken
+        let repr = (!self.impls.secret.is_once()).then(|| quote! { #[repr(transparent)] });
+
         quote! {
+            #eq
             #clone
-            #[derive(Hash, PartialEq, Eq)]
-            #[repr(transparent)]
+            #repr
             #owned_attrs
             #body
 
@@ -579,6 +1176,7 @@ impl<'a> OwnedCodeGen<'a> {
             #debug
             #display
             #secret
+            #zeroize
             #ord
             #serde
         }
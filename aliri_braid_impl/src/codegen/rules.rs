@@ -0,0 +1,601 @@
+use quote::{format_ident, quote};
+
+use super::{
+    symbol::{self, parse_expr_as_lit, parse_lit_into_string, parse_lit_into_usize},
+    AttrList, StdLib,
+};
+
+/// Declarative validation rules, from `validate(...)`
+pub struct Rules {
+    non_empty: bool,
+    min_len: Option<usize>,
+    max_len: Option<usize>,
+    prefix: Option<String>,
+    charset: Option<Charset>,
+}
+
+struct Charset {
+    spec: String,
+    ranges: Vec<(char, char)>,
+}
+
+impl Charset {
+    fn parse(spec: String) -> Result<Self, String> {
+        if spec.is_empty() {
+            return Err("`charset` must not be empty".to_owned());
+        }
+
+        let chars = spec.chars().collect::<Vec<_>>();
+        let mut ranges = Vec::new();
+        let mut i = 0;
+        while i < chars.len() {
+            if i + 2 < chars.len() && chars[i + 1] == '-' {
+                let (start, end) = (chars[i], chars[i + 2]);
+                if end < start {
+                    return Err(format!("invalid range `{start}-{end}` in `charset`"));
+                }
+                ranges.push((start, end));
+                i += 3;
+            } else {
+                ranges.push((chars[i], chars[i]));
+                i += 1;
+            }
+        }
+
+        Ok(Self { spec, ranges })
+    }
+
+    fn pattern(&self) -> proc_macro2::TokenStream {
+        let alternatives = self.ranges.iter().map(|&(start, end)| {
+            if start == end {
+                let c = proc_macro2::Literal::character(start);
+                quote! { #c }
+            } else {
+                let (start, end) = (
+                    proc_macro2::Literal::character(start),
+                    proc_macro2::Literal::character(end),
+                );
+                quote! { #start..=#end }
+            }
+        });
+
+        quote! { #(#alternatives)|* }
+    }
+
+    /// A pattern matching the allowed code points, as `u32` values
+    fn code_point_pattern(&self) -> proc_macro2::TokenStream {
+        let alternatives = self.ranges.iter().map(|&(start, end)| {
+            let (start, end) = (
+                proc_macro2::Literal::u32_suffixed(start.into()),
+                proc_macro2::Literal::u32_suffixed(end.into()),
+            );
+            quote! { #start..=#end }
+        });
+
+        quote! { #(#alternatives)|* }
+    }
+
+    fn is_ascii(&self) -> bool {
+        self.ranges.iter().all(|&(_, end)| end.is_ascii())
+    }
+}
+
+impl Rules {
+    pub fn parse(list: &syn::MetaList) -> Result<Self, syn::Error> {
+        let mut rules = Self {
+            non_empty: false,
+            min_len: None,
+            max_len: None,
+            prefix: None,
+            charset: None,
+        };
+
+        let args = list.parse_args_with(AttrList::parse_terminated)?;
+        if args.is_empty() {
+            return Err(syn::Error::new_spanned(
+                list,
+                "`validate` requires at least one rule",
+            ));
+        }
+
+        for arg in args {
+            match &arg {
+                syn::Meta::Path(p) if p == symbol::NON_EMPTY => {
+                    rules.non_empty = true;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::MIN_LEN => {
+                    rules.min_len = Some(parse_lit_into_usize(
+                        symbol::MIN_LEN,
+                        parse_expr_as_lit(&nv.value)?,
+                    )?);
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::MAX_LEN => {
+                    rules.max_len = Some(parse_lit_into_usize(
+                        symbol::MAX_LEN,
+                        parse_expr_as_lit(&nv.value)?,
+                    )?);
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::PREFIX => {
+                    let prefix =
+                        parse_lit_into_string(symbol::PREFIX, parse_expr_as_lit(&nv.value)?)?;
+                    if prefix.is_empty() {
+                        return Err(syn::Error::new_spanned(
+                            &nv.value,
+                            "`prefix` must not be empty",
+                        ));
+                    }
+                    rules.prefix = Some(prefix);
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::CHARSET => {
+                    let spec =
+                        parse_lit_into_string(symbol::CHARSET, parse_expr_as_lit(&nv.value)?)?;
+                    rules.charset = Some(
+                        Charset::parse(spec).map_err(|e| syn::Error::new_spanned(&nv.value, e))?,
+                    );
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        &arg,
+                        format!(
+                            "unsupported validate argument `{}`",
+                            quote::ToTokens::to_token_stream(arg.path())
+                        ),
+                    ));
+                }
+            }
+        }
+
+        if let (Some(min), Some(max)) = (rules.min_len, rules.max_len) {
+            if min > max {
+                return Err(syn::Error::new_spanned(
+                    list,
+                    "`min_len` must not be greater than `max_len`",
+                ));
+            }
+        }
+
+        if let (Some(prefix), Some(max)) = (&rules.prefix, rules.max_len) {
+            if prefix.len() > max {
+                return Err(syn::Error::new_spanned(
+                    list,
+                    "`prefix` must not be longer than `max_len`",
+                ));
+            }
+        }
+
+        Ok(rules)
+    }
+
+    /// The error type and `Validator` implementation for the type being validated
+    pub fn tokens(
+        &self,
+        ty: &syn::Ident,
+        vis: &syn::Visibility,
+        std_lib: &StdLib,
+        secret: bool,
+        has_alloc: bool,
+    ) -> proc_macro2::TokenStream {
+        let core = std_lib.core();
+        let error = format_ident!("Invalid{}", ty);
+
+        let mut variants = Vec::new();
+        let mut messages = Vec::new();
+        let mut checks = Vec::new();
+        let mut conversions = Vec::new();
+
+        if self.non_empty {
+            variants.push(quote! {
+                /// The value is empty
+                Empty,
+            });
+            messages.push(quote! {
+                Self::Empty => f.write_str("value must not be empty"),
+            });
+            checks.push(quote! {
+                if raw.is_empty() {
+                    return ::#core::result::Result::Err(#error::Empty);
+                }
+            });
+            conversions.push(quote! {
+                #error::Empty => ("non_empty", "value must not be empty", 0, 0),
+            });
+        }
+
+        if let Some(min) = self.min_len {
+            let doc = format!("The value is shorter than {min} bytes");
+            variants.push(quote! {
+                #[doc = #doc]
+                TooShort {
+                    /// The length of the value, in bytes
+                    len: usize,
+                },
+            });
+            messages.push(quote! {
+                Self::TooShort { len } => ::#core::write!(
+                    f,
+                    "value must be at least {} bytes long, but was {} bytes",
+                    #min,
+                    len,
+                ),
+            });
+            checks.push(quote! {
+                if raw.len() < #min {
+                    return ::#core::result::Result::Err(#error::TooShort { len: raw.len() });
+                }
+            });
+            let message = format!("value must be at least {min} bytes long");
+            conversions.push(quote! {
+                #error::TooShort { len } => ("min_len", #message, len, 0),
+            });
+        }
+
+        if let Some(max) = self.max_len {
+            let doc = format!("The value is longer than {max} bytes");
+            variants.push(quote! {
+                #[doc = #doc]
+                TooLong {
+                    /// The length of the value, in bytes
+                    len: usize,
+                },
+            });
+            messages.push(quote! {
+                Self::TooLong { len } => ::#core::write!(
+                    f,
+                    "value must be at most {} bytes long, but was {} bytes",
+                    #max,
+                    len,
+                ),
+            });
+            checks.push(quote! {
+                if raw.len() > #max {
+                    return ::#core::result::Result::Err(#error::TooLong { len: raw.len() });
+                }
+            });
+            let message = format!("value must be at most {max} bytes long");
+            conversions.push(quote! {
+                #error::TooLong { len } => ("max_len", #message, #max, len - #max),
+            });
+        }
+
+        if let Some(prefix) = &self.prefix {
+            let doc = format!("The value does not start with `{prefix}`");
+            let message = format!("value must start with `{prefix}`");
+            variants.push(quote! {
+                #[doc = #doc]
+                MissingPrefix,
+            });
+            messages.push(quote! {
+                Self::MissingPrefix => f.write_str(#message),
+            });
+            conversions.push(quote! {
+                #error::MissingPrefix => ("prefix", #message, 0, 0),
+            });
+        }
+
+        let rest = match (&self.prefix, &self.charset) {
+            (Some(prefix), Some(_)) => quote! {
+                let rest = match raw.strip_prefix(#prefix) {
+                    ::#core::option::Option::Some(rest) => rest,
+                    ::#core::option::Option::None => {
+                        return ::#core::result::Result::Err(#error::MissingPrefix);
+                    }
+                };
+            },
+            (Some(prefix), None) => quote! {
+                if !raw.starts_with(#prefix) {
+                    return ::#core::result::Result::Err(#error::MissingPrefix);
+                }
+            },
+            (None, Some(_)) => quote! {
+                let rest = raw;
+            },
+            (None, None) => quote! {},
+        };
+        checks.push(rest);
+
+        if let Some(charset) = &self.charset {
+            let spec = &charset.spec;
+            let doc = format!("The value contains a character not in `{spec}`");
+            variants.push(quote! {
+                #[doc = #doc]
+                InvalidChar {
+                    /// The rejected character
+                    ch: char,
+                    /// The byte offset of the rejected character in the value
+                    offset: usize,
+                },
+            });
+            messages.push(quote! {
+                Self::InvalidChar { ch, offset } => ::#core::write!(
+                    f,
+                    "invalid character {:?} at offset {}, expected one of `{}`",
+                    ch,
+                    offset,
+                    #spec,
+                ),
+            });
+
+            let message = format!("value must only contain characters in `{spec}`");
+            conversions.push(quote! {
+                #error::InvalidChar { ch, offset } => ("charset", #message, offset, ch.len_utf8()),
+            });
+
+            let pattern = charset.pattern();
+            checks.push(quote! {
+                let start = raw.len() - rest.len();
+                for (offset, ch) in rest.char_indices() {
+                    if !::#core::matches!(ch, #pattern) {
+                        return ::#core::result::Result::Err(#error::InvalidChar {
+                            ch,
+                            offset: start + offset,
+                        });
+                    }
+                }
+            });
+        }
+
+        let validate_all = has_alloc.then(|| self.validate_all(&error, std_lib));
+        let validate_const = self.validate_const(ty, vis, std_lib);
+        let ty_name = ty.to_string();
+        let doc = format!("The error produced when a [`{ty}`] breaks one of its validation rules");
+
+        quote! {
+            #[doc = #doc]
+            #[derive(Clone, Debug, PartialEq, Eq)]
+            #vis enum #error {
+                #(#variants)*
+            }
+
+            #[automatically_derived]
+            impl ::#core::fmt::Display for #error {
+                fn fmt(&self, f: &mut ::#core::fmt::Formatter) -> ::#core::fmt::Result {
+                    match self {
+                        #(#messages)*
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::error::Error for #error {}
+
+            #[automatically_derived]
+            impl ::#core::convert::From<::#core::convert::Infallible> for #error {
+                #[inline(always)]
+                fn from(x: ::#core::convert::Infallible) -> Self {
+                    match x {}
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::convert::From<#error> for ::aliri_braid::ValidationError {
+                fn from(error: #error) -> Self {
+                    let (rule, message, offset, len) = match error {
+                        #(#conversions)*
+                    };
+                    ::aliri_braid::ValidationError::new(rule, message, offset, len)
+                        .for_braid(#ty_name, #secret)
+                }
+            }
+
+            #[automatically_derived]
+            impl ::aliri_braid::Validator for #ty {
+                type Error = #error;
+
+                fn validate(raw: &str) -> ::#core::result::Result<(), Self::Error> {
+                    #(#checks)*
+                    ::#core::result::Result::Ok(())
+                }
+
+                #validate_all
+            }
+
+            #validate_const
+        }
+    }
+
+    /// `Validator::validate_all()`, which checks every rule rather than stopping at the first
+    /// broken one
+    ///
+    /// If the prefix is missing, the charset is checked against the whole value.
+    fn validate_all(&self, error: &syn::Ident, std_lib: &StdLib) -> proc_macro2::TokenStream {
+        let core = std_lib.core();
+        let alloc = std_lib.alloc();
+        let mut checks = Vec::new();
+
+        if self.non_empty {
+            checks.push(quote! {
+                if raw.is_empty() {
+                    errors.push(#error::Empty);
+                }
+            });
+        }
+
+        if let Some(min) = self.min_len {
+            checks.push(quote! {
+                if raw.len() < #min {
+                    errors.push(#error::TooShort { len: raw.len() });
+                }
+            });
+        }
+
+        if let Some(max) = self.max_len {
+            checks.push(quote! {
+                if raw.len() > #max {
+                    errors.push(#error::TooLong { len: raw.len() });
+                }
+            });
+        }
+
+        checks.push(match (&self.prefix, &self.charset) {
+            (Some(prefix), Some(_)) => quote! {
+                let rest = match raw.strip_prefix(#prefix) {
+                    ::#core::option::Option::Some(rest) => rest,
+                    ::#core::option::Option::None => {
+                        errors.push(#error::MissingPrefix);
+                        raw
+                    }
+                };
+            },
+            (Some(prefix), None) => quote! {
+                if !raw.starts_with(#prefix) {
+                    errors.push(#error::MissingPrefix);
+                }
+            },
+            (None, Some(_)) => quote! {
+                let rest = raw;
+            },
+            (None, None) => quote! {},
+        });
+
+        if let Some(charset) = &self.charset {
+            let pattern = charset.pattern();
+            checks.push(quote! {
+                let start = raw.len() - rest.len();
+                for (offset, ch) in rest.char_indices() {
+                    if !::#core::matches!(ch, #pattern) {
+                        errors.push(#error::InvalidChar {
+                            ch,
+                            offset: start + offset,
+                        });
+                    }
+                }
+            });
+        }
+
+        quote! {
+            fn validate_all(raw: &str) -> ::#core::result::Result<(), ::#alloc::vec::Vec<Self::Error>> {
+                let mut errors = ::#alloc::vec::Vec::new();
+                #(#checks)*
+                if errors.is_empty() {
+                    ::#core::result::Result::Ok(())
+                } else {
+                    ::#core::result::Result::Err(errors)
+                }
+            }
+        }
+    }
+
+    /// An inherent `validate_const()`, which checks the same rules in a `const` context
+    fn validate_const(
+        &self,
+        ty: &syn::Ident,
+        vis: &syn::Visibility,
+        std_lib: &StdLib,
+    ) -> proc_macro2::TokenStream {
+        let core = std_lib.core();
+        let mut checks = Vec::new();
+
+        if self.non_empty {
+            checks.push(quote! {
+                if raw.is_empty() {
+                    return ::#core::result::Result::Err("value must not be empty");
+                }
+            });
+        }
+
+        if let Some(min) = self.min_len {
+            let message = format!("value must be at least {min} bytes long");
+            checks.push(quote! {
+                if raw.len() < #min {
+                    return ::#core::result::Result::Err(#message);
+                }
+            });
+        }
+
+        if let Some(max) = self.max_len {
+            let message = format!("value must be at most {max} bytes long");
+            checks.push(quote! {
+                if raw.len() > #max {
+                    return ::#core::result::Result::Err(#message);
+                }
+            });
+        }
+
+        let start = if let Some(prefix) = &self.prefix {
+            let message = format!("value must start with `{prefix}`");
+            let prefix = proc_macro2::Literal::byte_string(prefix.as_bytes());
+            checks.push(quote! {
+                let prefix = #prefix;
+                if bytes.len() < prefix.len() {
+                    return ::#core::result::Result::Err(#message);
+                }
+                let mut i = 0;
+                while i < prefix.len() {
+                    if bytes[i] != prefix[i] {
+                        return ::#core::result::Result::Err(#message);
+                    }
+                    i += 1;
+                }
+            });
+            quote! { prefix.len() }
+        } else {
+            quote! { 0 }
+        };
+
+        if let Some(charset) = &self.charset {
+            let message = format!("value must only contain characters in `{}`", charset.spec);
+            let pattern = charset.code_point_pattern();
+
+            // Non-ASCII bytes can never match an ASCII charset, so need not be decoded
+            let decode = if charset.is_ascii() {
+                quote! {
+                    let (code_point, width) = (bytes[i] as u32, 1);
+                }
+            } else {
+                quote! {
+                    let b = bytes[i] as u32;
+                    let (code_point, width) = if b < 0x80 {
+                        (b, 1)
+                    } else if b < 0xE0 {
+                        ((b & 0x1F) << 6 | (bytes[i + 1] as u32 & 0x3F), 2)
+                    } else if b < 0xF0 {
+                        (
+                            (b & 0x0F) << 12
+                                | (bytes[i + 1] as u32 & 0x3F) << 6
+                                | (bytes[i + 2] as u32 & 0x3F),
+                            3,
+                        )
+                    } else {
+                        (
+                            (b & 0x07) << 18
+                                | (bytes[i + 1] as u32 & 0x3F) << 12
+                                | (bytes[i + 2] as u32 & 0x3F) << 6
+                                | (bytes[i + 3] as u32 & 0x3F),
+                            4,
+                        )
+                    };
+                }
+            };
+
+            checks.push(quote! {
+                let mut i = #start;
+                while i < bytes.len() {
+                    #decode
+                    if !::#core::matches!(code_point, #pattern) {
+                        return ::#core::result::Result::Err(#message);
+                    }
+                    i += width;
+                }
+            });
+        }
+
+        let bytes = (self.prefix.is_some() || self.charset.is_some())
+            .then(|| quote! { let bytes = raw.as_bytes(); });
+
+        quote! {
+            #[automatically_derived]
+            impl #ty {
+                /// Checks the validation rules of this type in a `const` context
+                ///
+                /// # Errors
+                ///
+                /// Returns a description of the first rule that the value breaks.
+                #vis const fn validate_const(raw: &str) -> ::#core::result::Result<(), &'static str> {
+                    #bytes
+                    #(#checks)*
+                    ::#core::result::Result::Ok(())
+                }
+            }
+        }
+    }
+}
@@ -11,6 +11,28 @@ pub const CLONE: Symbol = Symbol("clone");
 pub const DEBUG: Symbol = Symbol("debug");
 pub const DISPLAY: Symbol = Symbol("display");
 pub const SECRET: Symbol = Symbol("secret");
+pub const STRICT: Symbol = Symbol("strict");
+pub const MESSAGE: Symbol = Symbol("message");
+pub const LAST: Symbol = Symbol("last");
+pub const LENGTH_ONLY: Symbol = Symbol("length_only");
+pub const NONE: Symbol = Symbol("none");
+pub const FINGERPRINT: Symbol = Symbol("fingerprint");
+pub const AUDIT: Symbol = Symbol("audit");
+pub const LOAD: Symbol = Symbol("load");
+pub const SCRUB: Symbol = Symbol("scrub");
+pub const ONCE: Symbol = Symbol("once");
+pub const ZEROIZE: Symbol = Symbol("zeroize");
+pub const GENERATE: Symbol = Symbol("generate");
+pub const LEN: Symbol = Symbol("len");
+pub const ALPHABET: Symbol = Symbol("alphabet");
+pub const CONST_VALIDATOR: Symbol = Symbol("const_validator");
+pub const CONTEXT_VALIDATOR: Symbol = Symbol("context_validator");
+pub const VALIDATE: Symbol = Symbol("validate");
+pub const NON_EMPTY: Symbol = Symbol("non_empty");
+pub const MIN_LEN: Symbol = Symbol("min_len");
+pub const MAX_LEN: Symbol = Symbol("max_len");
+pub const CHARSET: Symbol = Symbol("charset");
+pub const PREFIX: Symbol = Symbol("prefix");
 pub const ORD: Symbol = Symbol("ord");
 pub const SERDE: Symbol = Symbol("serde");
 pub const REF: Symbol = Symbol("ref_name");
@@ -28,7 +50,7 @@ impl PartialEq<Symbol> for Ident {
     }
 }
 
-impl<'a> PartialEq<Symbol> for &'a Ident {
+impl PartialEq<Symbol> for &Ident {
     fn eq(&self, word: &Symbol) -> bool {
         *self == word.0
     }
@@ -40,7 +62,7 @@ impl PartialEq<Symbol> for Path {
     }
 }
 
-impl<'a> PartialEq<Symbol> for &'a Path {
+impl PartialEq<Symbol> for &Path {
     fn eq(&self, word: &Symbol) -> bool {
         self.is_ident(word.0)
     }
@@ -102,6 +124,20 @@ pub(super) fn parse_lit_into_string(
     Ok(string.value())
 }
 
+pub(super) fn parse_lit_into_usize(attr_name: Symbol, lit: &syn::Lit) -> Result<usize, syn::Error> {
+    if let syn::Lit::Int(int) = lit {
+        int.base10_parse()
+    } else {
+        Err(syn::Error::new_spanned(
+            lit,
+            format!(
+                "expected attribute `{}` to have an integer value (`{} = 4`)",
+                attr_name, attr_name
+            ),
+        ))
+    }
+}
+
 fn parse_lit_str<T>(s: &syn::LitStr) -> syn::parse::Result<T>
 where
     T: syn::parse::Parse,
@@ -43,8 +43,29 @@ use syn::parse_macro_input;
 /// * either `validator [ = "Type" ]` or `normalizer [ = "Type" ]`
 ///   * Indicates the type is validated or normalized. If not specified, it is assumed that the
 ///     braid implements the relevant trait itself.
-/// * `clone = "impl|omit"` (default: `impl`)
-///   * Changes the automatic derivation of a `Clone` implementation on the owned type.
+/// * `const_validator`
+///   * Indicates that the validator type provides an inherent `const fn validate_const(raw: &str)
+///     -> Result<(), &'static str>`, which makes the borrowed form's `from_static()` a `const fn`,
+///     so that invalid constants fail to compile.
+/// * `context_validator`
+///   * Indicates that the validator type also implements `ContextValidator`, and adds a
+///     `new_with(&context, raw)` constructor to the owned type and a `from_str_with(&context, raw)`
+///     constructor to the borrowed type, which check the rules of both traits. If `serde` is
+///     implemented, also generates a `DeserializeSeed` named after the type with a `Seed` suffix,
+///     which holds a reference to the context. `edit()` is replaced by `edit_with(&context, f)`.
+/// * `validate(...)`
+///   * Generates the `Validator` implementation from declarative rules, along with an error enum
+///     named `Invalid` followed by the type name, which converts into an
+///     `aliri_braid::ValidationError`. Accepts `non_empty`, `min_len = N`, `max_len = N`, `prefix =
+///     "..."`, and `charset = "..."`, where the charset lists the characters and ranges of
+///     characters, such as `a-z`, allowed after the prefix. Lengths are measured in bytes. Cannot
+///     be combined with `validator` or `normalizer`. Also generates `validate_const()`, as for
+///     `const_validator`, and a `validate_all()` that reports every broken rule.
+/// * `clone = "impl|explicit|omit"` (default: `impl`, or `omit` if `secret(once)`, which only
+///   supports `omit`)
+///   * Changes the automatic derivation of a `Clone` implementation on the owned type. If
+///     `explicit`, then an inherent `duplicate()` method is generated instead, which is reported to
+///     the `audit` hook of a secret braid.
 /// * `debug = "impl|owned|omit"` (default `impl`)
 ///   * Changes how automatic implementations of the `Debug` trait are provided. If `owned`, then
 ///     the owned type will generate a `Debug` implementation that will just delegate to the
@@ -53,12 +74,58 @@ use syn::parse_macro_input;
 ///   * Changes how automatic implementations of the `Display` trait are provided. If `owned`, then
 ///     the owned type will generate a `Display` implementation that will just delegate to the
 ///     borrowed implementation. If `omit`, then no implementations of `Display` will be provided.
-/// * `ord = "impl|owned|omit"` (default `impl`)
+/// * `secret [ = "impl|owned|omit|strict" ]` or `secret(...)`
+///   * Replaces the `Debug` and `Display` implementations with ones that redact the value unless
+///     formatted with the alternate flag, or unless overridden by the runtime policy in
+///     `aliri_braid::redaction`. Equality comparisons are made in constant time, and `ord` defaults
+///     to `omit`. Validation errors are wrapped in `aliri_braid::redaction::RedactedError` so that
+///     the rejected value is never formatted. The list form enables secret handling and accepts the
+///     following options:
+///     * `strict`: replaces `as_str()` with `expose_secret()` and `with_exposed()`, and omits
+///       `take()` and the `AsRef<str>`, `Borrow<str>`, and `From<Owned> for String`
+///       implementations, so that every access to the value is explicit. The value is not revealed
+///       by the alternate flag.
+///     * `zeroize`: wipes the value's memory when it is dropped. Requires the `zeroize` feature of
+///       `aliri_braid`.
+///     * `audit = "Hook"`: reports each access to the raw value to the given type, which must
+///       implement `aliri_braid::SecretAccessHook`.
+///     * `load`: adds `from_env()` and `from_file()` constructors that wipe any intermediate
+///       buffers and never include the value in errors. Requires the `std` feature of
+///       `aliri_braid`.
+///     * `scrub`: registers each owned value with `aliri_braid::Scrubber::global()` while it is
+///       alive. Requires the `scrub` feature of `aliri_braid`.
+///     * `once`: implies `strict`, but omits `expose_secret()` and `with_exposed()`, so that the
+///       value can only be read by `take_once()`, which moves it out of the owned form and fails on
+///       any later call. The value is never revealed by formatting or copied: `Clone`, `ToOwned`,
+///       and the conversions from the borrowed form are not implemented. Cannot be combined with
+///       `normalizer`, `serde = "expose"`, or `serde = "sealed"`.
+///     * `message = "..."`: redacts the value with a fixed message.
+///     * `last = N`: masks all but the last `N` characters of the value.
+///     * `length_only`: redacts the value with a message noting only its length.
+///     * `fingerprint`: includes a keyed fingerprint of the value in the redaction message, and
+///       adds a `fingerprint()` method to the borrowed form.
+///     * `none`: disables revealing the value with the alternate flag.
+///     * `debug(...)` or `display(...)`: applies the above redaction options to only the `Debug` or
+///       `Display` implementation.
+/// * `ord = "impl|owned|omit"` (default `impl`, or `omit` if `secret`)
 ///   * Changes how automatic implementations of the `PartialOrd` and `Ord` traits are provided. If
 ///     `owned`, then the owned type will generate implementations that will just delegate to the
 ///     borrowed implementations. If `omit`, then no implementations will be provided.
-/// * `serde = "impl|omit"` (default `omit`)
-///   * Adds serialize and deserialize implementations
+/// * `serde [ = "impl|omit|redact|error|expose|sealed" ]` (default `omit`)
+///   * Adds serialize and deserialize implementations. Braids marked `secret` must specify how the
+///     value is serialized: `redact` serializes the `Display` redaction message, `error` fails to
+///     serialize, and `expose` serializes the raw value. Deserialization is the same in these
+///     cases. `sealed` serializes the value encrypted by the sealer installed with
+///     `aliri_braid::seal::set_sealer()`, and unseals it before validating it on deserialization.
+///     Borrowed references cannot be deserialized from sealed values. Requires the `seal` feature
+///     of `aliri_braid`.
+/// * `generate(len = N, alphabet = "...")`
+///   * Adds `generate()` and `generate_with(rng)` constructors that produce random values of `N`
+///     characters, using the operating system's random number generator or the provided
+///     `rand_core::CryptoRngCore`. The alphabet may be `base62` (the default), `base64url`,
+///     `base32`, `hex`, or `digits`. Generated values are checked by the validator or normalizer,
+///     and are generated again if rejected. The wrapped type must implement `From<String>`.
+///     Requires the `generate` feature of `aliri_braid`.
 /// * `no_expose`
 ///   * Functions that expose the internal field type will not be exposed publicly.
 /// * `no_std`
@@ -79,17 +146,47 @@ pub fn braid(args: TokenStream, input: TokenStream) -> TokenStream {
 /// * either `validator [ = "Type" ]`
 ///   * Indicates the type is validated. If not specified, it is assumed that the braid implements
 ///     the relevant trait itself.
+/// * `const_validator`
+///   * Indicates that the validator type provides an inherent `const fn validate_const(raw: &str)
+///     -> Result<(), &'static str>`, which makes the borrowed form's `from_static()` a `const fn`,
+///     so that invalid constants fail to compile.
+/// * `context_validator`
+///   * Indicates that the validator type also implements `ContextValidator`, and adds a
+///     `from_str_with(&context, raw)` constructor, which checks the rules of both traits.
+/// * `validate(...)`
+///   * Generates the `Validator` implementation from declarative rules, along with an error enum
+///     named `Invalid` followed by the type name, which converts into an
+///     `aliri_braid::ValidationError`. Accepts `non_empty`, `min_len = N`, `max_len = N`, `prefix =
+///     "..."`, and `charset = "..."`, where the charset lists the characters and ranges of
+///     characters, such as `a-z`, allowed after the prefix. Lengths are measured in bytes. Cannot
+///     be combined with `validator`. Also generates `validate_const()`, as for `const_validator`,
+///     and, unless `no_std`, a `validate_all()` that reports every broken rule.
 /// * `debug = "impl|omit"` (default `impl`)
 ///   * Changes how automatic implementations of the `Debug` trait are provided. If `omit`, then no
 ///     implementations of `Debug` will be provided.
 /// * `display = "impl|omit"` (default `impl`)
 ///   * Changes how automatic implementations of the `Display` trait are provided. If `omit`, then
 ///     no implementations of `Display` will be provided.
-/// * `ord = "impl|omit"` (default `impl`)
+/// * `secret [ = "impl|omit|strict" ]` or `secret(...)`
+///   * Replaces the `Debug` and `Display` implementations with ones that redact the value unless
+///     formatted with the alternate flag, or unless overridden by the runtime policy in
+///     `aliri_braid::redaction`. Equality comparisons are made in constant time, and `ord` defaults
+///     to `omit`. Validation errors are wrapped in `aliri_braid::redaction::RedactedError` so that
+///     the rejected value is never formatted. The list form accepts the following options:
+///     * `strict`: replaces `as_str()` with `expose_secret()` and `with_exposed()`, and omits the
+///       `AsRef<str>` and `Borrow<str>` implementations. The value is not revealed by the alternate
+///       flag.
+///     * `audit = "Hook"`: reports each access to the raw value to the given type, which must
+///       implement `aliri_braid::SecretAccessHook`.
+///     * `message = "..."`, `last = N`, `length_only`, `fingerprint`, `none`, `debug(...)`, and
+///       `display(...)`: configure redaction in the same way as for [`braid`].
+/// * `ord = "impl|omit"` (default `impl`, or `omit` if `secret`)
 ///   * Changes how automatic implementations of the `PartialOrd` and `Ord` traits are provided. If
 ///     `omit`, then no implementations will be provided.
-/// * `serde = "impl|omit"` (default `omit`)
-///   * Adds serialize and deserialize implementations
+/// * `serde [ = "impl|omit|redact|error|expose" ]` (default `omit`)
+///   * Adds serialize and deserialize implementations. Braids marked `secret` must specify how the
+///     value is serialized: `redact` serializes the `Display` redaction message, `error` fails to
+///     serialize, and `expose` serializes the raw value. Deserialization is the same in all cases.
 /// * `no_std`
 ///   * Generates a `no_std`-compatible braid that doesn't require `alloc`
 #[proc_macro_attribute]
@@ -106,6 +203,10 @@ fn as_validator(validator: &syn::Type) -> proc_macro2::TokenStream {
     quote::quote! { <#validator as ::aliri_braid::Validator> }
 }
 
+fn as_context_validator(validator: &syn::Type) -> proc_macro2::TokenStream {
+    quote::quote! { <#validator as ::aliri_braid::ContextValidator> }
+}
+
 fn as_normalizer(normalizer: &syn::Type) -> proc_macro2::TokenStream {
     quote::quote! { <#normalizer as ::aliri_braid::Normalizer> }
 }